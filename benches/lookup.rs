@@ -0,0 +1,459 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use enum_map::EnumMap;
+
+/// Declare the same enum with every lookup strategy
+macro_rules! vendor_codes {
+    ($($variant:ident = $code:tt),* $(,)?) => {
+        #[derive(EnumMap, Debug, Clone, Copy, PartialEq, Eq)]
+        #[enum_map(code(lookup = "match", tables))]
+        enum MatchCodes {
+            $(#[mapstr(code = $code)] $variant,)*
+        }
+
+        #[derive(EnumMap, Debug, Clone, Copy, PartialEq, Eq)]
+        #[enum_map(code(lookup = "tree"))]
+        enum TreeCodes {
+            $(#[mapstr(code = $code)] $variant,)*
+        }
+
+        #[derive(EnumMap, Debug, Clone, Copy, PartialEq, Eq)]
+        #[enum_map(code(lookup = "phf"))]
+        enum PhfCodes {
+            $(#[mapstr(code = $code)] $variant,)*
+        }
+    };
+}
+
+vendor_codes! {
+    V0000 = "acme-0000", V0001 = "globex-7919", V0002 = "initech-5838", V0003 = "umbrella-3757", V0004 = "hooli-1676",
+    V0005 = "stark-9595", V0006 = "wayne-7514", V0007 = "tyrell-5433", V0008 = "cyberdyne-3352", V0009 = "soylent-1271",
+    V0010 = "acme-9190", V0011 = "globex-7109", V0012 = "initech-5028", V0013 = "umbrella-2947", V0014 = "hooli-0866",
+    V0015 = "stark-8785", V0016 = "wayne-6704", V0017 = "tyrell-4623", V0018 = "cyberdyne-2542", V0019 = "soylent-0461",
+    V0020 = "acme-8380", V0021 = "globex-6299", V0022 = "initech-4218", V0023 = "umbrella-2137", V0024 = "hooli-0056",
+    V0025 = "stark-7975", V0026 = "wayne-5894", V0027 = "tyrell-3813", V0028 = "cyberdyne-1732", V0029 = "soylent-9651",
+    V0030 = "acme-7570", V0031 = "globex-5489", V0032 = "initech-3408", V0033 = "umbrella-1327", V0034 = "hooli-9246",
+    V0035 = "stark-7165", V0036 = "wayne-5084", V0037 = "tyrell-3003", V0038 = "cyberdyne-0922", V0039 = "soylent-8841",
+    V0040 = "acme-6760", V0041 = "globex-4679", V0042 = "initech-2598", V0043 = "umbrella-0517", V0044 = "hooli-8436",
+    V0045 = "stark-6355", V0046 = "wayne-4274", V0047 = "tyrell-2193", V0048 = "cyberdyne-0112", V0049 = "soylent-8031",
+    V0050 = "acme-5950", V0051 = "globex-3869", V0052 = "initech-1788", V0053 = "umbrella-9707", V0054 = "hooli-7626",
+    V0055 = "stark-5545", V0056 = "wayne-3464", V0057 = "tyrell-1383", V0058 = "cyberdyne-9302", V0059 = "soylent-7221",
+    V0060 = "acme-5140", V0061 = "globex-3059", V0062 = "initech-0978", V0063 = "umbrella-8897", V0064 = "hooli-6816",
+    V0065 = "stark-4735", V0066 = "wayne-2654", V0067 = "tyrell-0573", V0068 = "cyberdyne-8492", V0069 = "soylent-6411",
+    V0070 = "acme-4330", V0071 = "globex-2249", V0072 = "initech-0168", V0073 = "umbrella-8087", V0074 = "hooli-6006",
+    V0075 = "stark-3925", V0076 = "wayne-1844", V0077 = "tyrell-9763", V0078 = "cyberdyne-7682", V0079 = "soylent-5601",
+    V0080 = "acme-3520", V0081 = "globex-1439", V0082 = "initech-9358", V0083 = "umbrella-7277", V0084 = "hooli-5196",
+    V0085 = "stark-3115", V0086 = "wayne-1034", V0087 = "tyrell-8953", V0088 = "cyberdyne-6872", V0089 = "soylent-4791",
+    V0090 = "acme-2710", V0091 = "globex-0629", V0092 = "initech-8548", V0093 = "umbrella-6467", V0094 = "hooli-4386",
+    V0095 = "stark-2305", V0096 = "wayne-0224", V0097 = "tyrell-8143", V0098 = "cyberdyne-6062", V0099 = "soylent-3981",
+    V0100 = "acme-1900", V0101 = "globex-9819", V0102 = "initech-7738", V0103 = "umbrella-5657", V0104 = "hooli-3576",
+    V0105 = "stark-1495", V0106 = "wayne-9414", V0107 = "tyrell-7333", V0108 = "cyberdyne-5252", V0109 = "soylent-3171",
+    V0110 = "acme-1090", V0111 = "globex-9009", V0112 = "initech-6928", V0113 = "umbrella-4847", V0114 = "hooli-2766",
+    V0115 = "stark-0685", V0116 = "wayne-8604", V0117 = "tyrell-6523", V0118 = "cyberdyne-4442", V0119 = "soylent-2361",
+    V0120 = "acme-0280", V0121 = "globex-8199", V0122 = "initech-6118", V0123 = "umbrella-4037", V0124 = "hooli-1956",
+    V0125 = "stark-9875", V0126 = "wayne-7794", V0127 = "tyrell-5713", V0128 = "cyberdyne-3632", V0129 = "soylent-1551",
+    V0130 = "acme-9470", V0131 = "globex-7389", V0132 = "initech-5308", V0133 = "umbrella-3227", V0134 = "hooli-1146",
+    V0135 = "stark-9065", V0136 = "wayne-6984", V0137 = "tyrell-4903", V0138 = "cyberdyne-2822", V0139 = "soylent-0741",
+    V0140 = "acme-8660", V0141 = "globex-6579", V0142 = "initech-4498", V0143 = "umbrella-2417", V0144 = "hooli-0336",
+    V0145 = "stark-8255", V0146 = "wayne-6174", V0147 = "tyrell-4093", V0148 = "cyberdyne-2012", V0149 = "soylent-9931",
+    V0150 = "acme-7850", V0151 = "globex-5769", V0152 = "initech-3688", V0153 = "umbrella-1607", V0154 = "hooli-9526",
+    V0155 = "stark-7445", V0156 = "wayne-5364", V0157 = "tyrell-3283", V0158 = "cyberdyne-1202", V0159 = "soylent-9121",
+    V0160 = "acme-7040", V0161 = "globex-4959", V0162 = "initech-2878", V0163 = "umbrella-0797", V0164 = "hooli-8716",
+    V0165 = "stark-6635", V0166 = "wayne-4554", V0167 = "tyrell-2473", V0168 = "cyberdyne-0392", V0169 = "soylent-8311",
+    V0170 = "acme-6230", V0171 = "globex-4149", V0172 = "initech-2068", V0173 = "umbrella-9987", V0174 = "hooli-7906",
+    V0175 = "stark-5825", V0176 = "wayne-3744", V0177 = "tyrell-1663", V0178 = "cyberdyne-9582", V0179 = "soylent-7501",
+    V0180 = "acme-5420", V0181 = "globex-3339", V0182 = "initech-1258", V0183 = "umbrella-9177", V0184 = "hooli-7096",
+    V0185 = "stark-5015", V0186 = "wayne-2934", V0187 = "tyrell-0853", V0188 = "cyberdyne-8772", V0189 = "soylent-6691",
+    V0190 = "acme-4610", V0191 = "globex-2529", V0192 = "initech-0448", V0193 = "umbrella-8367", V0194 = "hooli-6286",
+    V0195 = "stark-4205", V0196 = "wayne-2124", V0197 = "tyrell-0043", V0198 = "cyberdyne-7962", V0199 = "soylent-5881",
+    V0200 = "acme-3800", V0201 = "globex-1719", V0202 = "initech-9638", V0203 = "umbrella-7557", V0204 = "hooli-5476",
+    V0205 = "stark-3395", V0206 = "wayne-1314", V0207 = "tyrell-9233", V0208 = "cyberdyne-7152", V0209 = "soylent-5071",
+    V0210 = "acme-2990", V0211 = "globex-0909", V0212 = "initech-8828", V0213 = "umbrella-6747", V0214 = "hooli-4666",
+    V0215 = "stark-2585", V0216 = "wayne-0504", V0217 = "tyrell-8423", V0218 = "cyberdyne-6342", V0219 = "soylent-4261",
+    V0220 = "acme-2180", V0221 = "globex-0099", V0222 = "initech-8018", V0223 = "umbrella-5937", V0224 = "hooli-3856",
+    V0225 = "stark-1775", V0226 = "wayne-9694", V0227 = "tyrell-7613", V0228 = "cyberdyne-5532", V0229 = "soylent-3451",
+    V0230 = "acme-1370", V0231 = "globex-9289", V0232 = "initech-7208", V0233 = "umbrella-5127", V0234 = "hooli-3046",
+    V0235 = "stark-0965", V0236 = "wayne-8884", V0237 = "tyrell-6803", V0238 = "cyberdyne-4722", V0239 = "soylent-2641",
+    V0240 = "acme-0560", V0241 = "globex-8479", V0242 = "initech-6398", V0243 = "umbrella-4317", V0244 = "hooli-2236",
+    V0245 = "stark-0155", V0246 = "wayne-8074", V0247 = "tyrell-5993", V0248 = "cyberdyne-3912", V0249 = "soylent-1831",
+    V0250 = "acme-9750", V0251 = "globex-7669", V0252 = "initech-5588", V0253 = "umbrella-3507", V0254 = "hooli-1426",
+    V0255 = "stark-9345", V0256 = "wayne-7264", V0257 = "tyrell-5183", V0258 = "cyberdyne-3102", V0259 = "soylent-1021",
+    V0260 = "acme-8940", V0261 = "globex-6859", V0262 = "initech-4778", V0263 = "umbrella-2697", V0264 = "hooli-0616",
+    V0265 = "stark-8535", V0266 = "wayne-6454", V0267 = "tyrell-4373", V0268 = "cyberdyne-2292", V0269 = "soylent-0211",
+    V0270 = "acme-8130", V0271 = "globex-6049", V0272 = "initech-3968", V0273 = "umbrella-1887", V0274 = "hooli-9806",
+    V0275 = "stark-7725", V0276 = "wayne-5644", V0277 = "tyrell-3563", V0278 = "cyberdyne-1482", V0279 = "soylent-9401",
+    V0280 = "acme-7320", V0281 = "globex-5239", V0282 = "initech-3158", V0283 = "umbrella-1077", V0284 = "hooli-8996",
+    V0285 = "stark-6915", V0286 = "wayne-4834", V0287 = "tyrell-2753", V0288 = "cyberdyne-0672", V0289 = "soylent-8591",
+    V0290 = "acme-6510", V0291 = "globex-4429", V0292 = "initech-2348", V0293 = "umbrella-0267", V0294 = "hooli-8186",
+    V0295 = "stark-6105", V0296 = "wayne-4024", V0297 = "tyrell-1943", V0298 = "cyberdyne-9862", V0299 = "soylent-7781",
+    V0300 = "acme-5700", V0301 = "globex-3619", V0302 = "initech-1538", V0303 = "umbrella-9457", V0304 = "hooli-7376",
+    V0305 = "stark-5295", V0306 = "wayne-3214", V0307 = "tyrell-1133", V0308 = "cyberdyne-9052", V0309 = "soylent-6971",
+    V0310 = "acme-4890", V0311 = "globex-2809", V0312 = "initech-0728", V0313 = "umbrella-8647", V0314 = "hooli-6566",
+    V0315 = "stark-4485", V0316 = "wayne-2404", V0317 = "tyrell-0323", V0318 = "cyberdyne-8242", V0319 = "soylent-6161",
+    V0320 = "acme-4080", V0321 = "globex-1999", V0322 = "initech-9918", V0323 = "umbrella-7837", V0324 = "hooli-5756",
+    V0325 = "stark-3675", V0326 = "wayne-1594", V0327 = "tyrell-9513", V0328 = "cyberdyne-7432", V0329 = "soylent-5351",
+    V0330 = "acme-3270", V0331 = "globex-1189", V0332 = "initech-9108", V0333 = "umbrella-7027", V0334 = "hooli-4946",
+    V0335 = "stark-2865", V0336 = "wayne-0784", V0337 = "tyrell-8703", V0338 = "cyberdyne-6622", V0339 = "soylent-4541",
+    V0340 = "acme-2460", V0341 = "globex-0379", V0342 = "initech-8298", V0343 = "umbrella-6217", V0344 = "hooli-4136",
+    V0345 = "stark-2055", V0346 = "wayne-9974", V0347 = "tyrell-7893", V0348 = "cyberdyne-5812", V0349 = "soylent-3731",
+    V0350 = "acme-1650", V0351 = "globex-9569", V0352 = "initech-7488", V0353 = "umbrella-5407", V0354 = "hooli-3326",
+    V0355 = "stark-1245", V0356 = "wayne-9164", V0357 = "tyrell-7083", V0358 = "cyberdyne-5002", V0359 = "soylent-2921",
+    V0360 = "acme-0840", V0361 = "globex-8759", V0362 = "initech-6678", V0363 = "umbrella-4597", V0364 = "hooli-2516",
+    V0365 = "stark-0435", V0366 = "wayne-8354", V0367 = "tyrell-6273", V0368 = "cyberdyne-4192", V0369 = "soylent-2111",
+    V0370 = "acme-0030", V0371 = "globex-7949", V0372 = "initech-5868", V0373 = "umbrella-3787", V0374 = "hooli-1706",
+    V0375 = "stark-9625", V0376 = "wayne-7544", V0377 = "tyrell-5463", V0378 = "cyberdyne-3382", V0379 = "soylent-1301",
+    V0380 = "acme-9220", V0381 = "globex-7139", V0382 = "initech-5058", V0383 = "umbrella-2977", V0384 = "hooli-0896",
+    V0385 = "stark-8815", V0386 = "wayne-6734", V0387 = "tyrell-4653", V0388 = "cyberdyne-2572", V0389 = "soylent-0491",
+    V0390 = "acme-8410", V0391 = "globex-6329", V0392 = "initech-4248", V0393 = "umbrella-2167", V0394 = "hooli-0086",
+    V0395 = "stark-8005", V0396 = "wayne-5924", V0397 = "tyrell-3843", V0398 = "cyberdyne-1762", V0399 = "soylent-9681",
+    V0400 = "acme-7600", V0401 = "globex-5519", V0402 = "initech-3438", V0403 = "umbrella-1357", V0404 = "hooli-9276",
+    V0405 = "stark-7195", V0406 = "wayne-5114", V0407 = "tyrell-3033", V0408 = "cyberdyne-0952", V0409 = "soylent-8871",
+    V0410 = "acme-6790", V0411 = "globex-4709", V0412 = "initech-2628", V0413 = "umbrella-0547", V0414 = "hooli-8466",
+    V0415 = "stark-6385", V0416 = "wayne-4304", V0417 = "tyrell-2223", V0418 = "cyberdyne-0142", V0419 = "soylent-8061",
+    V0420 = "acme-5980", V0421 = "globex-3899", V0422 = "initech-1818", V0423 = "umbrella-9737", V0424 = "hooli-7656",
+    V0425 = "stark-5575", V0426 = "wayne-3494", V0427 = "tyrell-1413", V0428 = "cyberdyne-9332", V0429 = "soylent-7251",
+    V0430 = "acme-5170", V0431 = "globex-3089", V0432 = "initech-1008", V0433 = "umbrella-8927", V0434 = "hooli-6846",
+    V0435 = "stark-4765", V0436 = "wayne-2684", V0437 = "tyrell-0603", V0438 = "cyberdyne-8522", V0439 = "soylent-6441",
+    V0440 = "acme-4360", V0441 = "globex-2279", V0442 = "initech-0198", V0443 = "umbrella-8117", V0444 = "hooli-6036",
+    V0445 = "stark-3955", V0446 = "wayne-1874", V0447 = "tyrell-9793", V0448 = "cyberdyne-7712", V0449 = "soylent-5631",
+    V0450 = "acme-3550", V0451 = "globex-1469", V0452 = "initech-9388", V0453 = "umbrella-7307", V0454 = "hooli-5226",
+    V0455 = "stark-3145", V0456 = "wayne-1064", V0457 = "tyrell-8983", V0458 = "cyberdyne-6902", V0459 = "soylent-4821",
+    V0460 = "acme-2740", V0461 = "globex-0659", V0462 = "initech-8578", V0463 = "umbrella-6497", V0464 = "hooli-4416",
+    V0465 = "stark-2335", V0466 = "wayne-0254", V0467 = "tyrell-8173", V0468 = "cyberdyne-6092", V0469 = "soylent-4011",
+    V0470 = "acme-1930", V0471 = "globex-9849", V0472 = "initech-7768", V0473 = "umbrella-5687", V0474 = "hooli-3606",
+    V0475 = "stark-1525", V0476 = "wayne-9444", V0477 = "tyrell-7363", V0478 = "cyberdyne-5282", V0479 = "soylent-3201",
+    V0480 = "acme-1120", V0481 = "globex-9039", V0482 = "initech-6958", V0483 = "umbrella-4877", V0484 = "hooli-2796",
+    V0485 = "stark-0715", V0486 = "wayne-8634", V0487 = "tyrell-6553", V0488 = "cyberdyne-4472", V0489 = "soylent-2391",
+    V0490 = "acme-0310", V0491 = "globex-8229", V0492 = "initech-6148", V0493 = "umbrella-4067", V0494 = "hooli-1986",
+    V0495 = "stark-9905", V0496 = "wayne-7824", V0497 = "tyrell-5743", V0498 = "cyberdyne-3662", V0499 = "soylent-1581",
+    V0500 = "acme-9500", V0501 = "globex-7419", V0502 = "initech-5338", V0503 = "umbrella-3257", V0504 = "hooli-1176",
+    V0505 = "stark-9095", V0506 = "wayne-7014", V0507 = "tyrell-4933", V0508 = "cyberdyne-2852", V0509 = "soylent-0771",
+    V0510 = "acme-8690", V0511 = "globex-6609", V0512 = "initech-4528", V0513 = "umbrella-2447", V0514 = "hooli-0366",
+    V0515 = "stark-8285", V0516 = "wayne-6204", V0517 = "tyrell-4123", V0518 = "cyberdyne-2042", V0519 = "soylent-9961",
+    V0520 = "acme-7880", V0521 = "globex-5799", V0522 = "initech-3718", V0523 = "umbrella-1637", V0524 = "hooli-9556",
+    V0525 = "stark-7475", V0526 = "wayne-5394", V0527 = "tyrell-3313", V0528 = "cyberdyne-1232", V0529 = "soylent-9151",
+    V0530 = "acme-7070", V0531 = "globex-4989", V0532 = "initech-2908", V0533 = "umbrella-0827", V0534 = "hooli-8746",
+    V0535 = "stark-6665", V0536 = "wayne-4584", V0537 = "tyrell-2503", V0538 = "cyberdyne-0422", V0539 = "soylent-8341",
+    V0540 = "acme-6260", V0541 = "globex-4179", V0542 = "initech-2098", V0543 = "umbrella-0017", V0544 = "hooli-7936",
+    V0545 = "stark-5855", V0546 = "wayne-3774", V0547 = "tyrell-1693", V0548 = "cyberdyne-9612", V0549 = "soylent-7531",
+    V0550 = "acme-5450", V0551 = "globex-3369", V0552 = "initech-1288", V0553 = "umbrella-9207", V0554 = "hooli-7126",
+    V0555 = "stark-5045", V0556 = "wayne-2964", V0557 = "tyrell-0883", V0558 = "cyberdyne-8802", V0559 = "soylent-6721",
+    V0560 = "acme-4640", V0561 = "globex-2559", V0562 = "initech-0478", V0563 = "umbrella-8397", V0564 = "hooli-6316",
+    V0565 = "stark-4235", V0566 = "wayne-2154", V0567 = "tyrell-0073", V0568 = "cyberdyne-7992", V0569 = "soylent-5911",
+    V0570 = "acme-3830", V0571 = "globex-1749", V0572 = "initech-9668", V0573 = "umbrella-7587", V0574 = "hooli-5506",
+    V0575 = "stark-3425", V0576 = "wayne-1344", V0577 = "tyrell-9263", V0578 = "cyberdyne-7182", V0579 = "soylent-5101",
+    V0580 = "acme-3020", V0581 = "globex-0939", V0582 = "initech-8858", V0583 = "umbrella-6777", V0584 = "hooli-4696",
+    V0585 = "stark-2615", V0586 = "wayne-0534", V0587 = "tyrell-8453", V0588 = "cyberdyne-6372", V0589 = "soylent-4291",
+    V0590 = "acme-2210", V0591 = "globex-0129", V0592 = "initech-8048", V0593 = "umbrella-5967", V0594 = "hooli-3886",
+    V0595 = "stark-1805", V0596 = "wayne-9724", V0597 = "tyrell-7643", V0598 = "cyberdyne-5562", V0599 = "soylent-3481",
+    V0600 = "acme-1400", V0601 = "globex-9319", V0602 = "initech-7238", V0603 = "umbrella-5157", V0604 = "hooli-3076",
+    V0605 = "stark-0995", V0606 = "wayne-8914", V0607 = "tyrell-6833", V0608 = "cyberdyne-4752", V0609 = "soylent-2671",
+    V0610 = "acme-0590", V0611 = "globex-8509", V0612 = "initech-6428", V0613 = "umbrella-4347", V0614 = "hooli-2266",
+    V0615 = "stark-0185", V0616 = "wayne-8104", V0617 = "tyrell-6023", V0618 = "cyberdyne-3942", V0619 = "soylent-1861",
+    V0620 = "acme-9780", V0621 = "globex-7699", V0622 = "initech-5618", V0623 = "umbrella-3537", V0624 = "hooli-1456",
+    V0625 = "stark-9375", V0626 = "wayne-7294", V0627 = "tyrell-5213", V0628 = "cyberdyne-3132", V0629 = "soylent-1051",
+    V0630 = "acme-8970", V0631 = "globex-6889", V0632 = "initech-4808", V0633 = "umbrella-2727", V0634 = "hooli-0646",
+    V0635 = "stark-8565", V0636 = "wayne-6484", V0637 = "tyrell-4403", V0638 = "cyberdyne-2322", V0639 = "soylent-0241",
+    V0640 = "acme-8160", V0641 = "globex-6079", V0642 = "initech-3998", V0643 = "umbrella-1917", V0644 = "hooli-9836",
+    V0645 = "stark-7755", V0646 = "wayne-5674", V0647 = "tyrell-3593", V0648 = "cyberdyne-1512", V0649 = "soylent-9431",
+    V0650 = "acme-7350", V0651 = "globex-5269", V0652 = "initech-3188", V0653 = "umbrella-1107", V0654 = "hooli-9026",
+    V0655 = "stark-6945", V0656 = "wayne-4864", V0657 = "tyrell-2783", V0658 = "cyberdyne-0702", V0659 = "soylent-8621",
+    V0660 = "acme-6540", V0661 = "globex-4459", V0662 = "initech-2378", V0663 = "umbrella-0297", V0664 = "hooli-8216",
+    V0665 = "stark-6135", V0666 = "wayne-4054", V0667 = "tyrell-1973", V0668 = "cyberdyne-9892", V0669 = "soylent-7811",
+    V0670 = "acme-5730", V0671 = "globex-3649", V0672 = "initech-1568", V0673 = "umbrella-9487", V0674 = "hooli-7406",
+    V0675 = "stark-5325", V0676 = "wayne-3244", V0677 = "tyrell-1163", V0678 = "cyberdyne-9082", V0679 = "soylent-7001",
+    V0680 = "acme-4920", V0681 = "globex-2839", V0682 = "initech-0758", V0683 = "umbrella-8677", V0684 = "hooli-6596",
+    V0685 = "stark-4515", V0686 = "wayne-2434", V0687 = "tyrell-0353", V0688 = "cyberdyne-8272", V0689 = "soylent-6191",
+    V0690 = "acme-4110", V0691 = "globex-2029", V0692 = "initech-9948", V0693 = "umbrella-7867", V0694 = "hooli-5786",
+    V0695 = "stark-3705", V0696 = "wayne-1624", V0697 = "tyrell-9543", V0698 = "cyberdyne-7462", V0699 = "soylent-5381",
+    V0700 = "acme-3300", V0701 = "globex-1219", V0702 = "initech-9138", V0703 = "umbrella-7057", V0704 = "hooli-4976",
+    V0705 = "stark-2895", V0706 = "wayne-0814", V0707 = "tyrell-8733", V0708 = "cyberdyne-6652", V0709 = "soylent-4571",
+    V0710 = "acme-2490", V0711 = "globex-0409", V0712 = "initech-8328", V0713 = "umbrella-6247", V0714 = "hooli-4166",
+    V0715 = "stark-2085", V0716 = "wayne-0004", V0717 = "tyrell-7923", V0718 = "cyberdyne-5842", V0719 = "soylent-3761",
+    V0720 = "acme-1680", V0721 = "globex-9599", V0722 = "initech-7518", V0723 = "umbrella-5437", V0724 = "hooli-3356",
+    V0725 = "stark-1275", V0726 = "wayne-9194", V0727 = "tyrell-7113", V0728 = "cyberdyne-5032", V0729 = "soylent-2951",
+    V0730 = "acme-0870", V0731 = "globex-8789", V0732 = "initech-6708", V0733 = "umbrella-4627", V0734 = "hooli-2546",
+    V0735 = "stark-0465", V0736 = "wayne-8384", V0737 = "tyrell-6303", V0738 = "cyberdyne-4222", V0739 = "soylent-2141",
+    V0740 = "acme-0060", V0741 = "globex-7979", V0742 = "initech-5898", V0743 = "umbrella-3817", V0744 = "hooli-1736",
+    V0745 = "stark-9655", V0746 = "wayne-7574", V0747 = "tyrell-5493", V0748 = "cyberdyne-3412", V0749 = "soylent-1331",
+    V0750 = "acme-9250", V0751 = "globex-7169", V0752 = "initech-5088", V0753 = "umbrella-3007", V0754 = "hooli-0926",
+    V0755 = "stark-8845", V0756 = "wayne-6764", V0757 = "tyrell-4683", V0758 = "cyberdyne-2602", V0759 = "soylent-0521",
+    V0760 = "acme-8440", V0761 = "globex-6359", V0762 = "initech-4278", V0763 = "umbrella-2197", V0764 = "hooli-0116",
+    V0765 = "stark-8035", V0766 = "wayne-5954", V0767 = "tyrell-3873", V0768 = "cyberdyne-1792", V0769 = "soylent-9711",
+    V0770 = "acme-7630", V0771 = "globex-5549", V0772 = "initech-3468", V0773 = "umbrella-1387", V0774 = "hooli-9306",
+    V0775 = "stark-7225", V0776 = "wayne-5144", V0777 = "tyrell-3063", V0778 = "cyberdyne-0982", V0779 = "soylent-8901",
+    V0780 = "acme-6820", V0781 = "globex-4739", V0782 = "initech-2658", V0783 = "umbrella-0577", V0784 = "hooli-8496",
+    V0785 = "stark-6415", V0786 = "wayne-4334", V0787 = "tyrell-2253", V0788 = "cyberdyne-0172", V0789 = "soylent-8091",
+    V0790 = "acme-6010", V0791 = "globex-3929", V0792 = "initech-1848", V0793 = "umbrella-9767", V0794 = "hooli-7686",
+    V0795 = "stark-5605", V0796 = "wayne-3524", V0797 = "tyrell-1443", V0798 = "cyberdyne-9362", V0799 = "soylent-7281",
+    V0800 = "acme-5200", V0801 = "globex-3119", V0802 = "initech-1038", V0803 = "umbrella-8957", V0804 = "hooli-6876",
+    V0805 = "stark-4795", V0806 = "wayne-2714", V0807 = "tyrell-0633", V0808 = "cyberdyne-8552", V0809 = "soylent-6471",
+    V0810 = "acme-4390", V0811 = "globex-2309", V0812 = "initech-0228", V0813 = "umbrella-8147", V0814 = "hooli-6066",
+    V0815 = "stark-3985", V0816 = "wayne-1904", V0817 = "tyrell-9823", V0818 = "cyberdyne-7742", V0819 = "soylent-5661",
+    V0820 = "acme-3580", V0821 = "globex-1499", V0822 = "initech-9418", V0823 = "umbrella-7337", V0824 = "hooli-5256",
+    V0825 = "stark-3175", V0826 = "wayne-1094", V0827 = "tyrell-9013", V0828 = "cyberdyne-6932", V0829 = "soylent-4851",
+    V0830 = "acme-2770", V0831 = "globex-0689", V0832 = "initech-8608", V0833 = "umbrella-6527", V0834 = "hooli-4446",
+    V0835 = "stark-2365", V0836 = "wayne-0284", V0837 = "tyrell-8203", V0838 = "cyberdyne-6122", V0839 = "soylent-4041",
+    V0840 = "acme-1960", V0841 = "globex-9879", V0842 = "initech-7798", V0843 = "umbrella-5717", V0844 = "hooli-3636",
+    V0845 = "stark-1555", V0846 = "wayne-9474", V0847 = "tyrell-7393", V0848 = "cyberdyne-5312", V0849 = "soylent-3231",
+    V0850 = "acme-1150", V0851 = "globex-9069", V0852 = "initech-6988", V0853 = "umbrella-4907", V0854 = "hooli-2826",
+    V0855 = "stark-0745", V0856 = "wayne-8664", V0857 = "tyrell-6583", V0858 = "cyberdyne-4502", V0859 = "soylent-2421",
+    V0860 = "acme-0340", V0861 = "globex-8259", V0862 = "initech-6178", V0863 = "umbrella-4097", V0864 = "hooli-2016",
+    V0865 = "stark-9935", V0866 = "wayne-7854", V0867 = "tyrell-5773", V0868 = "cyberdyne-3692", V0869 = "soylent-1611",
+    V0870 = "acme-9530", V0871 = "globex-7449", V0872 = "initech-5368", V0873 = "umbrella-3287", V0874 = "hooli-1206",
+    V0875 = "stark-9125", V0876 = "wayne-7044", V0877 = "tyrell-4963", V0878 = "cyberdyne-2882", V0879 = "soylent-0801",
+    V0880 = "acme-8720", V0881 = "globex-6639", V0882 = "initech-4558", V0883 = "umbrella-2477", V0884 = "hooli-0396",
+    V0885 = "stark-8315", V0886 = "wayne-6234", V0887 = "tyrell-4153", V0888 = "cyberdyne-2072", V0889 = "soylent-9991",
+    V0890 = "acme-7910", V0891 = "globex-5829", V0892 = "initech-3748", V0893 = "umbrella-1667", V0894 = "hooli-9586",
+    V0895 = "stark-7505", V0896 = "wayne-5424", V0897 = "tyrell-3343", V0898 = "cyberdyne-1262", V0899 = "soylent-9181",
+    V0900 = "acme-7100", V0901 = "globex-5019", V0902 = "initech-2938", V0903 = "umbrella-0857", V0904 = "hooli-8776",
+    V0905 = "stark-6695", V0906 = "wayne-4614", V0907 = "tyrell-2533", V0908 = "cyberdyne-0452", V0909 = "soylent-8371",
+    V0910 = "acme-6290", V0911 = "globex-4209", V0912 = "initech-2128", V0913 = "umbrella-0047", V0914 = "hooli-7966",
+    V0915 = "stark-5885", V0916 = "wayne-3804", V0917 = "tyrell-1723", V0918 = "cyberdyne-9642", V0919 = "soylent-7561",
+    V0920 = "acme-5480", V0921 = "globex-3399", V0922 = "initech-1318", V0923 = "umbrella-9237", V0924 = "hooli-7156",
+    V0925 = "stark-5075", V0926 = "wayne-2994", V0927 = "tyrell-0913", V0928 = "cyberdyne-8832", V0929 = "soylent-6751",
+    V0930 = "acme-4670", V0931 = "globex-2589", V0932 = "initech-0508", V0933 = "umbrella-8427", V0934 = "hooli-6346",
+    V0935 = "stark-4265", V0936 = "wayne-2184", V0937 = "tyrell-0103", V0938 = "cyberdyne-8022", V0939 = "soylent-5941",
+    V0940 = "acme-3860", V0941 = "globex-1779", V0942 = "initech-9698", V0943 = "umbrella-7617", V0944 = "hooli-5536",
+    V0945 = "stark-3455", V0946 = "wayne-1374", V0947 = "tyrell-9293", V0948 = "cyberdyne-7212", V0949 = "soylent-5131",
+    V0950 = "acme-3050", V0951 = "globex-0969", V0952 = "initech-8888", V0953 = "umbrella-6807", V0954 = "hooli-4726",
+    V0955 = "stark-2645", V0956 = "wayne-0564", V0957 = "tyrell-8483", V0958 = "cyberdyne-6402", V0959 = "soylent-4321",
+    V0960 = "acme-2240", V0961 = "globex-0159", V0962 = "initech-8078", V0963 = "umbrella-5997", V0964 = "hooli-3916",
+    V0965 = "stark-1835", V0966 = "wayne-9754", V0967 = "tyrell-7673", V0968 = "cyberdyne-5592", V0969 = "soylent-3511",
+    V0970 = "acme-1430", V0971 = "globex-9349", V0972 = "initech-7268", V0973 = "umbrella-5187", V0974 = "hooli-3106",
+    V0975 = "stark-1025", V0976 = "wayne-8944", V0977 = "tyrell-6863", V0978 = "cyberdyne-4782", V0979 = "soylent-2701",
+    V0980 = "acme-0620", V0981 = "globex-8539", V0982 = "initech-6458", V0983 = "umbrella-4377", V0984 = "hooli-2296",
+    V0985 = "stark-0215", V0986 = "wayne-8134", V0987 = "tyrell-6053", V0988 = "cyberdyne-3972", V0989 = "soylent-1891",
+    V0990 = "acme-9810", V0991 = "globex-7729", V0992 = "initech-5648", V0993 = "umbrella-3567", V0994 = "hooli-1486",
+    V0995 = "stark-9405", V0996 = "wayne-7324", V0997 = "tyrell-5243", V0998 = "cyberdyne-3162", V0999 = "soylent-1081",
+    V1000 = "acme-9000", V1001 = "globex-6919", V1002 = "initech-4838", V1003 = "umbrella-2757", V1004 = "hooli-0676",
+    V1005 = "stark-8595", V1006 = "wayne-6514", V1007 = "tyrell-4433", V1008 = "cyberdyne-2352", V1009 = "soylent-0271",
+    V1010 = "acme-8190", V1011 = "globex-6109", V1012 = "initech-4028", V1013 = "umbrella-1947", V1014 = "hooli-9866",
+    V1015 = "stark-7785", V1016 = "wayne-5704", V1017 = "tyrell-3623", V1018 = "cyberdyne-1542", V1019 = "soylent-9461",
+    V1020 = "acme-7380", V1021 = "globex-5299", V1022 = "initech-3218", V1023 = "umbrella-1137", V1024 = "hooli-9056",
+    V1025 = "stark-6975", V1026 = "wayne-4894", V1027 = "tyrell-2813", V1028 = "cyberdyne-0732", V1029 = "soylent-8651",
+    V1030 = "acme-6570", V1031 = "globex-4489", V1032 = "initech-2408", V1033 = "umbrella-0327", V1034 = "hooli-8246",
+    V1035 = "stark-6165", V1036 = "wayne-4084", V1037 = "tyrell-2003", V1038 = "cyberdyne-9922", V1039 = "soylent-7841",
+    V1040 = "acme-5760", V1041 = "globex-3679", V1042 = "initech-1598", V1043 = "umbrella-9517", V1044 = "hooli-7436",
+    V1045 = "stark-5355", V1046 = "wayne-3274", V1047 = "tyrell-1193", V1048 = "cyberdyne-9112", V1049 = "soylent-7031",
+    V1050 = "acme-4950", V1051 = "globex-2869", V1052 = "initech-0788", V1053 = "umbrella-8707", V1054 = "hooli-6626",
+    V1055 = "stark-4545", V1056 = "wayne-2464", V1057 = "tyrell-0383", V1058 = "cyberdyne-8302", V1059 = "soylent-6221",
+    V1060 = "acme-4140", V1061 = "globex-2059", V1062 = "initech-9978", V1063 = "umbrella-7897", V1064 = "hooli-5816",
+    V1065 = "stark-3735", V1066 = "wayne-1654", V1067 = "tyrell-9573", V1068 = "cyberdyne-7492", V1069 = "soylent-5411",
+    V1070 = "acme-3330", V1071 = "globex-1249", V1072 = "initech-9168", V1073 = "umbrella-7087", V1074 = "hooli-5006",
+    V1075 = "stark-2925", V1076 = "wayne-0844", V1077 = "tyrell-8763", V1078 = "cyberdyne-6682", V1079 = "soylent-4601",
+    V1080 = "acme-2520", V1081 = "globex-0439", V1082 = "initech-8358", V1083 = "umbrella-6277", V1084 = "hooli-4196",
+    V1085 = "stark-2115", V1086 = "wayne-0034", V1087 = "tyrell-7953", V1088 = "cyberdyne-5872", V1089 = "soylent-3791",
+    V1090 = "acme-1710", V1091 = "globex-9629", V1092 = "initech-7548", V1093 = "umbrella-5467", V1094 = "hooli-3386",
+    V1095 = "stark-1305", V1096 = "wayne-9224", V1097 = "tyrell-7143", V1098 = "cyberdyne-5062", V1099 = "soylent-2981",
+    V1100 = "acme-0900", V1101 = "globex-8819", V1102 = "initech-6738", V1103 = "umbrella-4657", V1104 = "hooli-2576",
+    V1105 = "stark-0495", V1106 = "wayne-8414", V1107 = "tyrell-6333", V1108 = "cyberdyne-4252", V1109 = "soylent-2171",
+    V1110 = "acme-0090", V1111 = "globex-8009", V1112 = "initech-5928", V1113 = "umbrella-3847", V1114 = "hooli-1766",
+    V1115 = "stark-9685", V1116 = "wayne-7604", V1117 = "tyrell-5523", V1118 = "cyberdyne-3442", V1119 = "soylent-1361",
+    V1120 = "acme-9280", V1121 = "globex-7199", V1122 = "initech-5118", V1123 = "umbrella-3037", V1124 = "hooli-0956",
+    V1125 = "stark-8875", V1126 = "wayne-6794", V1127 = "tyrell-4713", V1128 = "cyberdyne-2632", V1129 = "soylent-0551",
+    V1130 = "acme-8470", V1131 = "globex-6389", V1132 = "initech-4308", V1133 = "umbrella-2227", V1134 = "hooli-0146",
+    V1135 = "stark-8065", V1136 = "wayne-5984", V1137 = "tyrell-3903", V1138 = "cyberdyne-1822", V1139 = "soylent-9741",
+    V1140 = "acme-7660", V1141 = "globex-5579", V1142 = "initech-3498", V1143 = "umbrella-1417", V1144 = "hooli-9336",
+    V1145 = "stark-7255", V1146 = "wayne-5174", V1147 = "tyrell-3093", V1148 = "cyberdyne-1012", V1149 = "soylent-8931",
+    V1150 = "acme-6850", V1151 = "globex-4769", V1152 = "initech-2688", V1153 = "umbrella-0607", V1154 = "hooli-8526",
+    V1155 = "stark-6445", V1156 = "wayne-4364", V1157 = "tyrell-2283", V1158 = "cyberdyne-0202", V1159 = "soylent-8121",
+    V1160 = "acme-6040", V1161 = "globex-3959", V1162 = "initech-1878", V1163 = "umbrella-9797", V1164 = "hooli-7716",
+    V1165 = "stark-5635", V1166 = "wayne-3554", V1167 = "tyrell-1473", V1168 = "cyberdyne-9392", V1169 = "soylent-7311",
+    V1170 = "acme-5230", V1171 = "globex-3149", V1172 = "initech-1068", V1173 = "umbrella-8987", V1174 = "hooli-6906",
+    V1175 = "stark-4825", V1176 = "wayne-2744", V1177 = "tyrell-0663", V1178 = "cyberdyne-8582", V1179 = "soylent-6501",
+    V1180 = "acme-4420", V1181 = "globex-2339", V1182 = "initech-0258", V1183 = "umbrella-8177", V1184 = "hooli-6096",
+    V1185 = "stark-4015", V1186 = "wayne-1934", V1187 = "tyrell-9853", V1188 = "cyberdyne-7772", V1189 = "soylent-5691",
+    V1190 = "acme-3610", V1191 = "globex-1529", V1192 = "initech-9448", V1193 = "umbrella-7367", V1194 = "hooli-5286",
+    V1195 = "stark-3205", V1196 = "wayne-1124", V1197 = "tyrell-9043", V1198 = "cyberdyne-6962", V1199 = "soylent-4881",
+    V1200 = "acme-2800", V1201 = "globex-0719", V1202 = "initech-8638", V1203 = "umbrella-6557", V1204 = "hooli-4476",
+    V1205 = "stark-2395", V1206 = "wayne-0314", V1207 = "tyrell-8233", V1208 = "cyberdyne-6152", V1209 = "soylent-4071",
+    V1210 = "acme-1990", V1211 = "globex-9909", V1212 = "initech-7828", V1213 = "umbrella-5747", V1214 = "hooli-3666",
+    V1215 = "stark-1585", V1216 = "wayne-9504", V1217 = "tyrell-7423", V1218 = "cyberdyne-5342", V1219 = "soylent-3261",
+    V1220 = "acme-1180", V1221 = "globex-9099", V1222 = "initech-7018", V1223 = "umbrella-4937", V1224 = "hooli-2856",
+    V1225 = "stark-0775", V1226 = "wayne-8694", V1227 = "tyrell-6613", V1228 = "cyberdyne-4532", V1229 = "soylent-2451",
+    V1230 = "acme-0370", V1231 = "globex-8289", V1232 = "initech-6208", V1233 = "umbrella-4127", V1234 = "hooli-2046",
+    V1235 = "stark-9965", V1236 = "wayne-7884", V1237 = "tyrell-5803", V1238 = "cyberdyne-3722", V1239 = "soylent-1641",
+    V1240 = "acme-9560", V1241 = "globex-7479", V1242 = "initech-5398", V1243 = "umbrella-3317", V1244 = "hooli-1236",
+    V1245 = "stark-9155", V1246 = "wayne-7074", V1247 = "tyrell-4993", V1248 = "cyberdyne-2912", V1249 = "soylent-0831",
+    V1250 = "acme-8750", V1251 = "globex-6669", V1252 = "initech-4588", V1253 = "umbrella-2507", V1254 = "hooli-0426",
+    V1255 = "stark-8345", V1256 = "wayne-6264", V1257 = "tyrell-4183", V1258 = "cyberdyne-2102", V1259 = "soylent-0021",
+    V1260 = "acme-7940", V1261 = "globex-5859", V1262 = "initech-3778", V1263 = "umbrella-1697", V1264 = "hooli-9616",
+    V1265 = "stark-7535", V1266 = "wayne-5454", V1267 = "tyrell-3373", V1268 = "cyberdyne-1292", V1269 = "soylent-9211",
+    V1270 = "acme-7130", V1271 = "globex-5049", V1272 = "initech-2968", V1273 = "umbrella-0887", V1274 = "hooli-8806",
+    V1275 = "stark-6725", V1276 = "wayne-4644", V1277 = "tyrell-2563", V1278 = "cyberdyne-0482", V1279 = "soylent-8401",
+    V1280 = "acme-6320", V1281 = "globex-4239", V1282 = "initech-2158", V1283 = "umbrella-0077", V1284 = "hooli-7996",
+    V1285 = "stark-5915", V1286 = "wayne-3834", V1287 = "tyrell-1753", V1288 = "cyberdyne-9672", V1289 = "soylent-7591",
+    V1290 = "acme-5510", V1291 = "globex-3429", V1292 = "initech-1348", V1293 = "umbrella-9267", V1294 = "hooli-7186",
+    V1295 = "stark-5105", V1296 = "wayne-3024", V1297 = "tyrell-0943", V1298 = "cyberdyne-8862", V1299 = "soylent-6781",
+    V1300 = "acme-4700", V1301 = "globex-2619", V1302 = "initech-0538", V1303 = "umbrella-8457", V1304 = "hooli-6376",
+    V1305 = "stark-4295", V1306 = "wayne-2214", V1307 = "tyrell-0133", V1308 = "cyberdyne-8052", V1309 = "soylent-5971",
+    V1310 = "acme-3890", V1311 = "globex-1809", V1312 = "initech-9728", V1313 = "umbrella-7647", V1314 = "hooli-5566",
+    V1315 = "stark-3485", V1316 = "wayne-1404", V1317 = "tyrell-9323", V1318 = "cyberdyne-7242", V1319 = "soylent-5161",
+    V1320 = "acme-3080", V1321 = "globex-0999", V1322 = "initech-8918", V1323 = "umbrella-6837", V1324 = "hooli-4756",
+    V1325 = "stark-2675", V1326 = "wayne-0594", V1327 = "tyrell-8513", V1328 = "cyberdyne-6432", V1329 = "soylent-4351",
+    V1330 = "acme-2270", V1331 = "globex-0189", V1332 = "initech-8108", V1333 = "umbrella-6027", V1334 = "hooli-3946",
+    V1335 = "stark-1865", V1336 = "wayne-9784", V1337 = "tyrell-7703", V1338 = "cyberdyne-5622", V1339 = "soylent-3541",
+    V1340 = "acme-1460", V1341 = "globex-9379", V1342 = "initech-7298", V1343 = "umbrella-5217", V1344 = "hooli-3136",
+    V1345 = "stark-1055", V1346 = "wayne-8974", V1347 = "tyrell-6893", V1348 = "cyberdyne-4812", V1349 = "soylent-2731",
+    V1350 = "acme-0650", V1351 = "globex-8569", V1352 = "initech-6488", V1353 = "umbrella-4407", V1354 = "hooli-2326",
+    V1355 = "stark-0245", V1356 = "wayne-8164", V1357 = "tyrell-6083", V1358 = "cyberdyne-4002", V1359 = "soylent-1921",
+    V1360 = "acme-9840", V1361 = "globex-7759", V1362 = "initech-5678", V1363 = "umbrella-3597", V1364 = "hooli-1516",
+    V1365 = "stark-9435", V1366 = "wayne-7354", V1367 = "tyrell-5273", V1368 = "cyberdyne-3192", V1369 = "soylent-1111",
+    V1370 = "acme-9030", V1371 = "globex-6949", V1372 = "initech-4868", V1373 = "umbrella-2787", V1374 = "hooli-0706",
+    V1375 = "stark-8625", V1376 = "wayne-6544", V1377 = "tyrell-4463", V1378 = "cyberdyne-2382", V1379 = "soylent-0301",
+    V1380 = "acme-8220", V1381 = "globex-6139", V1382 = "initech-4058", V1383 = "umbrella-1977", V1384 = "hooli-9896",
+    V1385 = "stark-7815", V1386 = "wayne-5734", V1387 = "tyrell-3653", V1388 = "cyberdyne-1572", V1389 = "soylent-9491",
+    V1390 = "acme-7410", V1391 = "globex-5329", V1392 = "initech-3248", V1393 = "umbrella-1167", V1394 = "hooli-9086",
+    V1395 = "stark-7005", V1396 = "wayne-4924", V1397 = "tyrell-2843", V1398 = "cyberdyne-0762", V1399 = "soylent-8681",
+    V1400 = "acme-6600", V1401 = "globex-4519", V1402 = "initech-2438", V1403 = "umbrella-0357", V1404 = "hooli-8276",
+    V1405 = "stark-6195", V1406 = "wayne-4114", V1407 = "tyrell-2033", V1408 = "cyberdyne-9952", V1409 = "soylent-7871",
+    V1410 = "acme-5790", V1411 = "globex-3709", V1412 = "initech-1628", V1413 = "umbrella-9547", V1414 = "hooli-7466",
+    V1415 = "stark-5385", V1416 = "wayne-3304", V1417 = "tyrell-1223", V1418 = "cyberdyne-9142", V1419 = "soylent-7061",
+    V1420 = "acme-4980", V1421 = "globex-2899", V1422 = "initech-0818", V1423 = "umbrella-8737", V1424 = "hooli-6656",
+    V1425 = "stark-4575", V1426 = "wayne-2494", V1427 = "tyrell-0413", V1428 = "cyberdyne-8332", V1429 = "soylent-6251",
+    V1430 = "acme-4170", V1431 = "globex-2089", V1432 = "initech-0008", V1433 = "umbrella-7927", V1434 = "hooli-5846",
+    V1435 = "stark-3765", V1436 = "wayne-1684", V1437 = "tyrell-9603", V1438 = "cyberdyne-7522", V1439 = "soylent-5441",
+    V1440 = "acme-3360", V1441 = "globex-1279", V1442 = "initech-9198", V1443 = "umbrella-7117", V1444 = "hooli-5036",
+    V1445 = "stark-2955", V1446 = "wayne-0874", V1447 = "tyrell-8793", V1448 = "cyberdyne-6712", V1449 = "soylent-4631",
+    V1450 = "acme-2550", V1451 = "globex-0469", V1452 = "initech-8388", V1453 = "umbrella-6307", V1454 = "hooli-4226",
+    V1455 = "stark-2145", V1456 = "wayne-0064", V1457 = "tyrell-7983", V1458 = "cyberdyne-5902", V1459 = "soylent-3821",
+    V1460 = "acme-1740", V1461 = "globex-9659", V1462 = "initech-7578", V1463 = "umbrella-5497", V1464 = "hooli-3416",
+    V1465 = "stark-1335", V1466 = "wayne-9254", V1467 = "tyrell-7173", V1468 = "cyberdyne-5092", V1469 = "soylent-3011",
+    V1470 = "acme-0930", V1471 = "globex-8849", V1472 = "initech-6768", V1473 = "umbrella-4687", V1474 = "hooli-2606",
+    V1475 = "stark-0525", V1476 = "wayne-8444", V1477 = "tyrell-6363", V1478 = "cyberdyne-4282", V1479 = "soylent-2201",
+    V1480 = "acme-0120", V1481 = "globex-8039", V1482 = "initech-5958", V1483 = "umbrella-3877", V1484 = "hooli-1796",
+    V1485 = "stark-9715", V1486 = "wayne-7634", V1487 = "tyrell-5553", V1488 = "cyberdyne-3472", V1489 = "soylent-1391",
+    V1490 = "acme-9310", V1491 = "globex-7229", V1492 = "initech-5148", V1493 = "umbrella-3067", V1494 = "hooli-0986",
+    V1495 = "stark-8905", V1496 = "wayne-6824", V1497 = "tyrell-4743", V1498 = "cyberdyne-2662", V1499 = "soylent-0581",
+    V1500 = "acme-8500", V1501 = "globex-6419", V1502 = "initech-4338", V1503 = "umbrella-2257", V1504 = "hooli-0176",
+    V1505 = "stark-8095", V1506 = "wayne-6014", V1507 = "tyrell-3933", V1508 = "cyberdyne-1852", V1509 = "soylent-9771",
+    V1510 = "acme-7690", V1511 = "globex-5609", V1512 = "initech-3528", V1513 = "umbrella-1447", V1514 = "hooli-9366",
+    V1515 = "stark-7285", V1516 = "wayne-5204", V1517 = "tyrell-3123", V1518 = "cyberdyne-1042", V1519 = "soylent-8961",
+    V1520 = "acme-6880", V1521 = "globex-4799", V1522 = "initech-2718", V1523 = "umbrella-0637", V1524 = "hooli-8556",
+    V1525 = "stark-6475", V1526 = "wayne-4394", V1527 = "tyrell-2313", V1528 = "cyberdyne-0232", V1529 = "soylent-8151",
+    V1530 = "acme-6070", V1531 = "globex-3989", V1532 = "initech-1908", V1533 = "umbrella-9827", V1534 = "hooli-7746",
+    V1535 = "stark-5665", V1536 = "wayne-3584", V1537 = "tyrell-1503", V1538 = "cyberdyne-9422", V1539 = "soylent-7341",
+    V1540 = "acme-5260", V1541 = "globex-3179", V1542 = "initech-1098", V1543 = "umbrella-9017", V1544 = "hooli-6936",
+    V1545 = "stark-4855", V1546 = "wayne-2774", V1547 = "tyrell-0693", V1548 = "cyberdyne-8612", V1549 = "soylent-6531",
+    V1550 = "acme-4450", V1551 = "globex-2369", V1552 = "initech-0288", V1553 = "umbrella-8207", V1554 = "hooli-6126",
+    V1555 = "stark-4045", V1556 = "wayne-1964", V1557 = "tyrell-9883", V1558 = "cyberdyne-7802", V1559 = "soylent-5721",
+    V1560 = "acme-3640", V1561 = "globex-1559", V1562 = "initech-9478", V1563 = "umbrella-7397", V1564 = "hooli-5316",
+    V1565 = "stark-3235", V1566 = "wayne-1154", V1567 = "tyrell-9073", V1568 = "cyberdyne-6992", V1569 = "soylent-4911",
+    V1570 = "acme-2830", V1571 = "globex-0749", V1572 = "initech-8668", V1573 = "umbrella-6587", V1574 = "hooli-4506",
+    V1575 = "stark-2425", V1576 = "wayne-0344", V1577 = "tyrell-8263", V1578 = "cyberdyne-6182", V1579 = "soylent-4101",
+    V1580 = "acme-2020", V1581 = "globex-9939", V1582 = "initech-7858", V1583 = "umbrella-5777", V1584 = "hooli-3696",
+    V1585 = "stark-1615", V1586 = "wayne-9534", V1587 = "tyrell-7453", V1588 = "cyberdyne-5372", V1589 = "soylent-3291",
+    V1590 = "acme-1210", V1591 = "globex-9129", V1592 = "initech-7048", V1593 = "umbrella-4967", V1594 = "hooli-2886",
+    V1595 = "stark-0805", V1596 = "wayne-8724", V1597 = "tyrell-6643", V1598 = "cyberdyne-4562", V1599 = "soylent-2481",
+    V1600 = "acme-0400", V1601 = "globex-8319", V1602 = "initech-6238", V1603 = "umbrella-4157", V1604 = "hooli-2076",
+    V1605 = "stark-9995", V1606 = "wayne-7914", V1607 = "tyrell-5833", V1608 = "cyberdyne-3752", V1609 = "soylent-1671",
+    V1610 = "acme-9590", V1611 = "globex-7509", V1612 = "initech-5428", V1613 = "umbrella-3347", V1614 = "hooli-1266",
+    V1615 = "stark-9185", V1616 = "wayne-7104", V1617 = "tyrell-5023", V1618 = "cyberdyne-2942", V1619 = "soylent-0861",
+    V1620 = "acme-8780", V1621 = "globex-6699", V1622 = "initech-4618", V1623 = "umbrella-2537", V1624 = "hooli-0456",
+    V1625 = "stark-8375", V1626 = "wayne-6294", V1627 = "tyrell-4213", V1628 = "cyberdyne-2132", V1629 = "soylent-0051",
+    V1630 = "acme-7970", V1631 = "globex-5889", V1632 = "initech-3808", V1633 = "umbrella-1727", V1634 = "hooli-9646",
+    V1635 = "stark-7565", V1636 = "wayne-5484", V1637 = "tyrell-3403", V1638 = "cyberdyne-1322", V1639 = "soylent-9241",
+    V1640 = "acme-7160", V1641 = "globex-5079", V1642 = "initech-2998", V1643 = "umbrella-0917", V1644 = "hooli-8836",
+    V1645 = "stark-6755", V1646 = "wayne-4674", V1647 = "tyrell-2593", V1648 = "cyberdyne-0512", V1649 = "soylent-8431",
+    V1650 = "acme-6350", V1651 = "globex-4269", V1652 = "initech-2188", V1653 = "umbrella-0107", V1654 = "hooli-8026",
+    V1655 = "stark-5945", V1656 = "wayne-3864", V1657 = "tyrell-1783", V1658 = "cyberdyne-9702", V1659 = "soylent-7621",
+    V1660 = "acme-5540", V1661 = "globex-3459", V1662 = "initech-1378", V1663 = "umbrella-9297", V1664 = "hooli-7216",
+    V1665 = "stark-5135", V1666 = "wayne-3054", V1667 = "tyrell-0973", V1668 = "cyberdyne-8892", V1669 = "soylent-6811",
+    V1670 = "acme-4730", V1671 = "globex-2649", V1672 = "initech-0568", V1673 = "umbrella-8487", V1674 = "hooli-6406",
+    V1675 = "stark-4325", V1676 = "wayne-2244", V1677 = "tyrell-0163", V1678 = "cyberdyne-8082", V1679 = "soylent-6001",
+    V1680 = "acme-3920", V1681 = "globex-1839", V1682 = "initech-9758", V1683 = "umbrella-7677", V1684 = "hooli-5596",
+    V1685 = "stark-3515", V1686 = "wayne-1434", V1687 = "tyrell-9353", V1688 = "cyberdyne-7272", V1689 = "soylent-5191",
+    V1690 = "acme-3110", V1691 = "globex-1029", V1692 = "initech-8948", V1693 = "umbrella-6867", V1694 = "hooli-4786",
+    V1695 = "stark-2705", V1696 = "wayne-0624", V1697 = "tyrell-8543", V1698 = "cyberdyne-6462", V1699 = "soylent-4381",
+    V1700 = "acme-2300", V1701 = "globex-0219", V1702 = "initech-8138", V1703 = "umbrella-6057", V1704 = "hooli-3976",
+    V1705 = "stark-1895", V1706 = "wayne-9814", V1707 = "tyrell-7733", V1708 = "cyberdyne-5652", V1709 = "soylent-3571",
+    V1710 = "acme-1490", V1711 = "globex-9409", V1712 = "initech-7328", V1713 = "umbrella-5247", V1714 = "hooli-3166",
+    V1715 = "stark-1085", V1716 = "wayne-9004", V1717 = "tyrell-6923", V1718 = "cyberdyne-4842", V1719 = "soylent-2761",
+    V1720 = "acme-0680", V1721 = "globex-8599", V1722 = "initech-6518", V1723 = "umbrella-4437", V1724 = "hooli-2356",
+    V1725 = "stark-0275", V1726 = "wayne-8194", V1727 = "tyrell-6113", V1728 = "cyberdyne-4032", V1729 = "soylent-1951",
+    V1730 = "acme-9870", V1731 = "globex-7789", V1732 = "initech-5708", V1733 = "umbrella-3627", V1734 = "hooli-1546",
+    V1735 = "stark-9465", V1736 = "wayne-7384", V1737 = "tyrell-5303", V1738 = "cyberdyne-3222", V1739 = "soylent-1141",
+    V1740 = "acme-9060", V1741 = "globex-6979", V1742 = "initech-4898", V1743 = "umbrella-2817", V1744 = "hooli-0736",
+    V1745 = "stark-8655", V1746 = "wayne-6574", V1747 = "tyrell-4493", V1748 = "cyberdyne-2412", V1749 = "soylent-0331",
+    V1750 = "acme-8250", V1751 = "globex-6169", V1752 = "initech-4088", V1753 = "umbrella-2007", V1754 = "hooli-9926",
+    V1755 = "stark-7845", V1756 = "wayne-5764", V1757 = "tyrell-3683", V1758 = "cyberdyne-1602", V1759 = "soylent-9521",
+    V1760 = "acme-7440", V1761 = "globex-5359", V1762 = "initech-3278", V1763 = "umbrella-1197", V1764 = "hooli-9116",
+    V1765 = "stark-7035", V1766 = "wayne-4954", V1767 = "tyrell-2873", V1768 = "cyberdyne-0792", V1769 = "soylent-8711",
+    V1770 = "acme-6630", V1771 = "globex-4549", V1772 = "initech-2468", V1773 = "umbrella-0387", V1774 = "hooli-8306",
+    V1775 = "stark-6225", V1776 = "wayne-4144", V1777 = "tyrell-2063", V1778 = "cyberdyne-9982", V1779 = "soylent-7901",
+    V1780 = "acme-5820", V1781 = "globex-3739", V1782 = "initech-1658", V1783 = "umbrella-9577", V1784 = "hooli-7496",
+    V1785 = "stark-5415", V1786 = "wayne-3334", V1787 = "tyrell-1253", V1788 = "cyberdyne-9172", V1789 = "soylent-7091",
+    V1790 = "acme-5010", V1791 = "globex-2929", V1792 = "initech-0848", V1793 = "umbrella-8767", V1794 = "hooli-6686",
+    V1795 = "stark-4605", V1796 = "wayne-2524", V1797 = "tyrell-0443", V1798 = "cyberdyne-8362", V1799 = "soylent-6281",
+    V1800 = "acme-4200", V1801 = "globex-2119", V1802 = "initech-0038", V1803 = "umbrella-7957", V1804 = "hooli-5876",
+    V1805 = "stark-3795", V1806 = "wayne-1714", V1807 = "tyrell-9633", V1808 = "cyberdyne-7552", V1809 = "soylent-5471",
+    V1810 = "acme-3390", V1811 = "globex-1309", V1812 = "initech-9228", V1813 = "umbrella-7147", V1814 = "hooli-5066",
+    V1815 = "stark-2985", V1816 = "wayne-0904", V1817 = "tyrell-8823", V1818 = "cyberdyne-6742", V1819 = "soylent-4661",
+    V1820 = "acme-2580", V1821 = "globex-0499", V1822 = "initech-8418", V1823 = "umbrella-6337", V1824 = "hooli-4256",
+    V1825 = "stark-2175", V1826 = "wayne-0094", V1827 = "tyrell-8013", V1828 = "cyberdyne-5932", V1829 = "soylent-3851",
+    V1830 = "acme-1770", V1831 = "globex-9689", V1832 = "initech-7608", V1833 = "umbrella-5527", V1834 = "hooli-3446",
+    V1835 = "stark-1365", V1836 = "wayne-9284", V1837 = "tyrell-7203", V1838 = "cyberdyne-5122", V1839 = "soylent-3041",
+    V1840 = "acme-0960", V1841 = "globex-8879", V1842 = "initech-6798", V1843 = "umbrella-4717", V1844 = "hooli-2636",
+    V1845 = "stark-0555", V1846 = "wayne-8474", V1847 = "tyrell-6393", V1848 = "cyberdyne-4312", V1849 = "soylent-2231",
+    V1850 = "acme-0150", V1851 = "globex-8069", V1852 = "initech-5988", V1853 = "umbrella-3907", V1854 = "hooli-1826",
+    V1855 = "stark-9745", V1856 = "wayne-7664", V1857 = "tyrell-5583", V1858 = "cyberdyne-3502", V1859 = "soylent-1421",
+    V1860 = "acme-9340", V1861 = "globex-7259", V1862 = "initech-5178", V1863 = "umbrella-3097", V1864 = "hooli-1016",
+    V1865 = "stark-8935", V1866 = "wayne-6854", V1867 = "tyrell-4773", V1868 = "cyberdyne-2692", V1869 = "soylent-0611",
+    V1870 = "acme-8530", V1871 = "globex-6449", V1872 = "initech-4368", V1873 = "umbrella-2287", V1874 = "hooli-0206",
+    V1875 = "stark-8125", V1876 = "wayne-6044", V1877 = "tyrell-3963", V1878 = "cyberdyne-1882", V1879 = "soylent-9801",
+    V1880 = "acme-7720", V1881 = "globex-5639", V1882 = "initech-3558", V1883 = "umbrella-1477", V1884 = "hooli-9396",
+    V1885 = "stark-7315", V1886 = "wayne-5234", V1887 = "tyrell-3153", V1888 = "cyberdyne-1072", V1889 = "soylent-8991",
+    V1890 = "acme-6910", V1891 = "globex-4829", V1892 = "initech-2748", V1893 = "umbrella-0667", V1894 = "hooli-8586",
+    V1895 = "stark-6505", V1896 = "wayne-4424", V1897 = "tyrell-2343", V1898 = "cyberdyne-0262", V1899 = "soylent-8181",
+    V1900 = "acme-6100", V1901 = "globex-4019", V1902 = "initech-1938", V1903 = "umbrella-9857", V1904 = "hooli-7776",
+    V1905 = "stark-5695", V1906 = "wayne-3614", V1907 = "tyrell-1533", V1908 = "cyberdyne-9452", V1909 = "soylent-7371",
+    V1910 = "acme-5290", V1911 = "globex-3209", V1912 = "initech-1128", V1913 = "umbrella-9047", V1914 = "hooli-6966",
+    V1915 = "stark-4885", V1916 = "wayne-2804", V1917 = "tyrell-0723", V1918 = "cyberdyne-8642", V1919 = "soylent-6561",
+    V1920 = "acme-4480", V1921 = "globex-2399", V1922 = "initech-0318", V1923 = "umbrella-8237", V1924 = "hooli-6156",
+    V1925 = "stark-4075", V1926 = "wayne-1994", V1927 = "tyrell-9913", V1928 = "cyberdyne-7832", V1929 = "soylent-5751",
+    V1930 = "acme-3670", V1931 = "globex-1589", V1932 = "initech-9508", V1933 = "umbrella-7427", V1934 = "hooli-5346",
+    V1935 = "stark-3265", V1936 = "wayne-1184", V1937 = "tyrell-9103", V1938 = "cyberdyne-7022", V1939 = "soylent-4941",
+    V1940 = "acme-2860", V1941 = "globex-0779", V1942 = "initech-8698", V1943 = "umbrella-6617", V1944 = "hooli-4536",
+    V1945 = "stark-2455", V1946 = "wayne-0374", V1947 = "tyrell-8293", V1948 = "cyberdyne-6212", V1949 = "soylent-4131",
+    V1950 = "acme-2050", V1951 = "globex-9969", V1952 = "initech-7888", V1953 = "umbrella-5807", V1954 = "hooli-3726",
+    V1955 = "stark-1645", V1956 = "wayne-9564", V1957 = "tyrell-7483", V1958 = "cyberdyne-5402", V1959 = "soylent-3321",
+    V1960 = "acme-1240", V1961 = "globex-9159", V1962 = "initech-7078", V1963 = "umbrella-4997", V1964 = "hooli-2916",
+    V1965 = "stark-0835", V1966 = "wayne-8754", V1967 = "tyrell-6673", V1968 = "cyberdyne-4592", V1969 = "soylent-2511",
+    V1970 = "acme-0430", V1971 = "globex-8349", V1972 = "initech-6268", V1973 = "umbrella-4187", V1974 = "hooli-2106",
+    V1975 = "stark-0025", V1976 = "wayne-7944", V1977 = "tyrell-5863", V1978 = "cyberdyne-3782", V1979 = "soylent-1701",
+    V1980 = "acme-9620", V1981 = "globex-7539", V1982 = "initech-5458", V1983 = "umbrella-3377", V1984 = "hooli-1296",
+    V1985 = "stark-9215", V1986 = "wayne-7134", V1987 = "tyrell-5053", V1988 = "cyberdyne-2972", V1989 = "soylent-0891",
+    V1990 = "acme-8810", V1991 = "globex-6729", V1992 = "initech-4648", V1993 = "umbrella-2567", V1994 = "hooli-0486",
+    V1995 = "stark-8405", V1996 = "wayne-6324", V1997 = "tyrell-4243", V1998 = "cyberdyne-2162", V1999 = "soylent-0081",
+}
+
+/// Every 16th code and some values that are not mapped
+fn inputs() -> Vec<&'static str> {
+    let mut inputs: Vec<_> = MatchCodes::CODE_STRS.iter().copied().step_by(16).collect();
+    inputs.extend(["acme-", "unknown-0000", "wayne-99999", ""]);
+    inputs
+}
+
+fn lookup(c: &mut Criterion) {
+    let inputs = inputs();
+    for s in MatchCodes::CODE_STRS {
+        let expected = MatchCodes::try_from_code(s).map(|v| v.to_code());
+        assert_eq!(TreeCodes::try_from_code(s).map(|v| v.to_code()), expected);
+        assert_eq!(PhfCodes::try_from_code(s).map(|v| v.to_code()), expected);
+    }
+
+    let mut group = c.benchmark_group("from_2000_variants");
+    group.bench_function("match", |b| {
+        b.iter(|| inputs.iter().filter_map(|s| MatchCodes::try_from_code(black_box(s))).count())
+    });
+    group.bench_function("tree", |b| {
+        b.iter(|| inputs.iter().filter_map(|s| TreeCodes::try_from_code(black_box(s))).count())
+    });
+    group.bench_function("phf", |b| {
+        b.iter(|| inputs.iter().filter_map(|s| PhfCodes::try_from_code(black_box(s))).count())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, lookup);
+criterion_main!(benches);
@@ -0,0 +1,96 @@
+/// Case convention used to derive mapped values from variant names
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+    Title,
+}
+
+impl RenameRule {
+    /// All supported conventions as they are written in `rename_all`
+    pub(crate) const ALL: &'static [(&'static str, Self)] = &[
+        ("lowercase", Self::Lower),
+        ("UPPERCASE", Self::Upper),
+        ("PascalCase", Self::Pascal),
+        ("camelCase", Self::Camel),
+        ("snake_case", Self::Snake),
+        ("SCREAMING_SNAKE_CASE", Self::ScreamingSnake),
+        ("kebab-case", Self::Kebab),
+        ("SCREAMING-KEBAB-CASE", Self::ScreamingKebab),
+        ("Title Case", Self::Title),
+    ];
+
+    pub(crate) fn from_str(s: &str) -> Option<Self> {
+        Self::ALL.iter().find(|(name, _)| *name == s).map(|(_, rule)| *rule)
+    }
+
+    /// Convert identifier like `HttpServer`, `HTTPServer` or `http_server` to this case
+    pub(crate) fn apply(&self, ident: &str) -> String {
+        let words = split_words(ident);
+        let capitalize = |w: &str| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(c) => c.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+                None => String::new(),
+            }
+        };
+        let join = |sep: &str, f: &dyn Fn(&str) -> String| words.iter().map(|w| f(w)).collect::<Vec<_>>().join(sep);
+
+        match self {
+            Self::Lower => join("", &|w| w.to_lowercase()),
+            Self::Upper => join("", &|w| w.to_uppercase()),
+            Self::Pascal => join("", &capitalize),
+            Self::Camel => {
+                let pascal = join("", &capitalize);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(c) => c.to_lowercase().chain(chars).collect(),
+                    None => pascal,
+                }
+            }
+            Self::Snake => join("_", &|w| w.to_lowercase()),
+            Self::ScreamingSnake => join("_", &|w| w.to_uppercase()),
+            Self::Kebab => join("-", &|w| w.to_lowercase()),
+            Self::ScreamingKebab => join("-", &|w| w.to_uppercase()),
+            Self::Title => join(" ", &capitalize),
+        }
+    }
+}
+
+/// Split identifier into words at underscores and case changes. Digits belong to the preceding word and runs of
+/// uppercase letters are kept together, e.g. `HTTPServer2Error` -> `HTTP`, `Server2`, `Error`.
+fn split_words(ident: &str) -> Vec<String> {
+    let ident = ident.strip_prefix("r#").unwrap_or(ident);
+    let chars: Vec<char> = ident.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !word.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if !prev.is_uppercase() || next_is_lower {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
@@ -1,10 +1,12 @@
-use itertools::{Either, Itertools};
 use proc_macro::TokenStream;
 use proc_macro2::{Ident};
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 use syn::{parse::Parse, parse_macro_input, spanned::Spanned, Token};
 
+use crate::case::RenameRule;
 use crate::helpers::{Error, MultiError};
+use crate::lookup::{self, Lookup};
+use crate::template::{FieldRef, Segment, Template};
 
 mod kw {
     syn::custom_keyword!(name);
@@ -15,6 +17,32 @@ mod kw {
     syn::custom_keyword!(default);
     syn::custom_keyword!(r#try);
     syn::custom_keyword!(display);
+    syn::custom_keyword!(fields);
+    syn::custom_keyword!(from_str);
+    syn::custom_keyword!(try_from_str);
+    syn::custom_keyword!(into_str);
+    syn::custom_keyword!(as_ref);
+    syn::custom_keyword!(trim);
+    syn::custom_keyword!(ignore_case);
+    syn::custom_keyword!(ignore_ascii_case);
+    syn::custom_keyword!(ignore_chars);
+    syn::custom_keyword!(aliases);
+    syn::custom_keyword!(allow_duplicate);
+    syn::custom_keyword!(primary);
+    syn::custom_keyword!(exhaustive);
+    syn::custom_keyword!(mapping);
+    syn::custom_keyword!(rename_all);
+    syn::custom_keyword!(ty);
+    syn::custom_keyword!(lit_macro);
+    syn::custom_keyword!(serde);
+    syn::custom_keyword!(serde_with);
+    syn::custom_keyword!(clap);
+    syn::custom_keyword!(sql);
+    syn::custom_keyword!(tables);
+    syn::custom_keyword!(sql_ddl);
+    syn::custom_keyword!(parse_prefix);
+    syn::custom_keyword!(const_from);
+    syn::custom_keyword!(lookup);
 }
 
 /// Main entry of #[derive(EnumMap)] macro
@@ -22,45 +50,313 @@ pub(crate) fn enum_map(item: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(item as syn::ItemEnum);
     let enum_ident = &ast.ident;
     let enum_vis = &ast.vis;
+    let enum_generics = &ast.generics;
 
-    let mut mapings = match Mapings::parse(&ast.variants) {
+    let Mapings {
+        mapings,
+        value_mapings,
+        meta,
+        variant_consts,
+        ..
+    } = match Mapings::parse(&ast.attrs, &ast.variants) {
         Ok(mapings) => mapings,
         Err(e) => return e.to_compile_error().into(),
     };
 
-    let expansions = mapings.iter_mut().map(|m| m.expand(enum_ident, enum_vis));
+    let expansions = mapings
+        .iter()
+        .map(|m| m.expand(enum_ident, enum_vis, enum_generics));
+    let value_expansions = value_mapings
+        .iter()
+        .map(|m| m.expand(enum_ident, enum_vis, enum_generics));
+
+    let meta_expansion = meta.expand(enum_ident, enum_vis, enum_generics);
+
+    let variant_consts = if variant_consts {
+        create_variant_consts(&ast)
+    } else {
+        quote! {}
+    };
+
+    let parse_error = if mapings.iter().any(|m| m.uses_parse_error()) {
+        create_parse_error(enum_ident, enum_vis)
+    } else {
+        quote! {}
+    };
 
     TokenStream::from(quote! {
         #(#expansions)*
+        #(#value_expansions)*
+        #meta_expansion
+        #variant_consts
+        #parse_error
     })
 }
 
+/// Create `VARIANT_COUNT` constant and `ALL` constant with every variant if the enum has only unit variants
+fn create_variant_consts(ast: &syn::ItemEnum) -> proc_macro2::TokenStream {
+    let eident = &ast.ident;
+    let evis = &ast.vis;
+    let count = ast.variants.len();
+
+    let all = ast
+        .variants
+        .iter()
+        .all(|v| matches!(v.fields, syn::Fields::Unit))
+        .then(|| {
+            let variants = ast.variants.iter().map(|v| &v.ident);
+            quote! {
+                /// All variants in declaration order
+                #evis const ALL: &'static [Self] = &[#(Self::#variants),*];
+            }
+        });
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    quote! {
+        impl #impl_generics #eident #ty_generics #where_clause {
+            /// Number of variants
+            #evis const VARIANT_COUNT: usize = #count;
+            #all
+        }
+    }
+}
+
+fn parse_error_ident(eident: &Ident) -> Ident {
+    format_ident!("Parse{}Error", eident)
+}
+
+/// SQL string literal, quotes are escaped by doubling them
+fn sql_string(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+/// Quoted SQL identifier, quotes are escaped by doubling them
+fn sql_ident(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\"\""))
+}
+
+/// Doc comment of the variant with lines joined by spaces
+fn variant_doc(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<_> = attrs
+        .iter()
+        .filter(|a| a.path.is_ident("doc"))
+        .filter_map(|a| match a.parse_meta() {
+            Ok(syn::Meta::NameValue(syn::MetaNameValue { lit: syn::Lit::Str(s), .. })) => Some(s.value()),
+            _ => None,
+        })
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect();
+    (!lines.is_empty()).then(|| lines.join(" "))
+}
+
+/// Create error type `Parse<Enum>Error` returned when string cannot be parsed into enum
+fn create_parse_error(eident: &Ident, evis: &syn::Visibility) -> proc_macro2::TokenStream {
+    let error_ident = parse_error_ident(eident);
+    let doc = format!("Error returned when string cannot be parsed into [`{eident}`]");
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #evis struct #error_ident {
+            /// String that failed to parse
+            #evis input: ::std::string::String,
+            /// Name of the maping used to parse
+            #evis mapping: &'static str,
+            /// Values that would have been accepted
+            #evis expected: &'static [&'static str],
+        }
+
+        impl ::std::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::write!(f, "invalid {} value `{}`, expected one of: ", self.mapping, self.input)?;
+                for (i, e) in self.expected.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    ::std::write!(f, "`{}`", e)?;
+                }
+                ::std::result::Result::Ok(())
+            }
+        }
+
+        impl ::std::error::Error for #error_ident {}
+    }
+}
+
 /// Struct to parse variants and hold intermediate state
 struct Mapings {
     mapings: Vec<Maping>,
+    /// Mapings to other types than strings
+    value_mapings: Vec<ValueMaping>,
+    /// Metadata records from `#[mapmeta(..)]`
+    meta: MetaTable,
     errors: MultiError,
-    is_display_implemented: bool
+    /// Traits implemented by mapings, (trait, maping name)
+    implemented_traits: Vec<(&'static str, String)>,
+    /// Create `VARIANT_COUNT` and `ALL` constants, set by `#[enum_map(tables)]`
+    variant_consts: bool,
 }
 
 impl Mapings {
-    fn parse(variants: &syn::punctuated::Punctuated<syn::Variant, Token![,]>) -> syn::Result<Vec<Maping>> {
+    fn parse(
+        attrs: &[syn::Attribute],
+        variants: &syn::punctuated::Punctuated<syn::Variant, Token![,]>,
+    ) -> syn::Result<Self> {
         let mut s = Self {
             mapings: Vec::new(),
+            value_mapings: Vec::new(),
+            meta: MetaTable::default(),
             errors: MultiError::new(),
-            is_display_implemented: false
+            implemented_traits: Vec::new(),
+            variant_consts: false,
         };
+
+        attrs
+            .iter()
+            .filter(|a| a.path.is_ident("enum_map"))
+            .for_each(|a| s.parse_enum_attribute(a));
+
+        for a in attrs.iter().filter(|a| a.path.is_ident("mapmeta")) {
+            if let Err(e) = s.meta.parse_declaration(a) {
+                s.errors.update(e);
+            }
+        }
     
         variants
             .iter()
             .for_each(|v| s.parse_variant(v));
+
+        for m in s.mapings.iter_mut() {
+            m.add_renamed_rules(variants);
+            if let Err(e) = m.resolve_default_variant() {
+                s.errors.update(e);
+            }
+        }
+
+        for m in s.mapings.iter_mut() {
+            m.unmapped = variants
+                .iter()
+                .map(|v| &v.ident)
+                .filter(|v| !m.rules.iter().any(|r| &r.variant == *v))
+                .cloned()
+                .collect();
+
+            if let Some(span) = m.impl_clap {
+                if variants.iter().any(|v| !matches!(v.fields, syn::Fields::Unit)) {
+                    s.errors.update(Error::unit_variants_only("clap", span).into());
+                }
+                m.clap_variants = variants
+                    .iter()
+                    .filter(|v| !m.unmapped.contains(&v.ident))
+                    .map(|v| (v.ident.clone(), variant_doc(&v.attrs)))
+                    .collect();
+            }
+        }
+
+        for m in s.mapings.iter() {
+            if let Err(e) = m.validate() {
+                s.errors.update(e);
+            }
+        }
+
+        // Names of constants created by `tables` and `sql_ddl` only differ by case of maping names
+        for (suffix, spans) in [
+            ("STRS", s.mapings.iter().map(|m| m.tables).collect::<Vec<_>>()),
+            ("SQL_CHECK", s.mapings.iter().map(|m| m.sql_ddl).collect()),
+        ] {
+            let mut names: Vec<String> = Vec::new();
+            for (m, span) in s.mapings.iter().zip(spans) {
+                let span = match span {
+                    Some(span) => span,
+                    None => continue,
+                };
+                let name = format!("{}_{suffix}", m.name.to_uppercase());
+                if names.contains(&name) {
+                    s.errors.update(Error::generated_name_collision(&name, span).into());
+                } else {
+                    names.push(name);
+                }
+            }
+        }
+
+        for m in s.value_mapings.iter_mut() {
+            if let Err(e) = m.finish(variants) {
+                s.errors.update(e);
+            }
+        }
+
+        if let Err(e) = s.meta.finish(variants) {
+            s.errors.update(e);
+        }
     
-        s.errors.inner.map(|_| s.mapings)
+        let errors = std::mem::replace(&mut s.errors, MultiError::new());
+        errors.inner.map(|_| s)
+    }
+
+    /// Check that traits are implemented only by one maping
+    fn claim_traits(
+        implemented_traits: &mut Vec<(&'static str, String)>,
+        options: &MapingOptions,
+        maping_name: &str,
+    ) -> syn::Result<()> {
+        let traits = [
+            ("Display", options.impl_display.map(|kw| kw.span())),
+            ("FromStr", options.impl_from_str.map(|kw| kw.span())),
+            ("[Try]From<&str>", options.impl_try_from_str.map(|kw| kw.span())),
+            ("[Try]From<Self> for &'static str", options.impl_into_str.map(|kw| kw.span())),
+            ("AsRef<str>", options.impl_as_ref.map(|kw| kw.span())),
+            ("Serialize/Deserialize", options.impl_serde.map(|kw| kw.span())),
+            ("ValueEnum", options.impl_clap.map(|kw| kw.span())),
+            ("ToSql/FromSql", options.impl_sql.map(|kw| kw.span())),
+        ];
+        for (tr, span) in traits {
+            let span = match span {
+                Some(span) => span,
+                None => continue,
+            };
+            match implemented_traits.iter().find(|(t, _)| *t == tr) {
+                // Some other maping is already implementing the trait
+                Some((_, m)) if m != maping_name => return Err(Error::trait_already_implemented(tr, span).into()),
+                Some(_) => {}
+                None => implemented_traits.push((tr, String::from(maping_name))),
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse #[enum_map(..)] on the enum, declared mapings are added before mapings found on variants
+    fn parse_enum_attribute(&mut self, attr: &syn::Attribute) {
+        let args = match attr.parse_args_with(EnumMapArguments::parse) {
+            Ok(args) => args,
+            Err(e) => return self.errors.update(e),
+        };
+
+        self.variant_consts |= args.tables.is_some();
+        for decl in args.mapings {
+            let name = decl.name.value();
+            if self.mapings.iter().any(|m| m.name == name) || self.value_mapings.iter().any(|m| m.name == name) {
+                self.errors.update(Error::duplicate_maping(&name, decl.name.span()).into());
+                continue;
+            }
+            if let Some(ty) = decl.ty {
+                self.value_mapings.push(ValueMaping::new(name, ty, decl.default_variant, decl.options));
+                continue;
+            }
+            if let Err(e) = Self::claim_traits(&mut self.implemented_traits, &decl.options, &name) {
+                self.errors.update(e);
+            }
+            let mut maping = Maping::new(name);
+            maping.rename_all = decl.rename_all;
+            maping.default_variant = decl.default_variant;
+            maping.update(decl.options);
+            self.mapings.push(maping);
+        }
     }
 
     /// Parse single variant
     fn parse_variant(&mut self, variant: &syn::Variant) {
         let mut mapstr_idx: usize = 0;
-        let has_fields = !variant.fields.is_empty();
+        let fields = VariantFields::new(&variant.fields);
         let mut mapings_on_this_variant: Vec<String> = Vec::new();
 
         variant
@@ -72,7 +368,7 @@ impl Mapings {
                     if let Err(e) = self.parse_mapstr_attribute(
                         &variant.ident,
                         mapstr_idx,
-                        has_fields,
+                        &fields,
                         a,
                         &mut mapings_on_this_variant
                     ) {
@@ -81,26 +377,181 @@ impl Mapings {
 
                     mapstr_idx += 1;
                 }
+                s if s.ident == "mapval" => {
+                    if let Err(e) = self.parse_mapval_attribute(&variant.ident, &fields, a) {
+                        self.errors.update(e);
+                    }
+                }
+                s if s.ident == "mapmeta" => {
+                    if let Err(e) = self.meta.parse_variant_attribute(&variant.ident, a) {
+                        self.errors.update(e);
+                    }
+                }
                 _ => {}
             });
     }
 
+    /// Parse single #[mapval(..)]
+    fn parse_mapval_attribute(&mut self, vident: &Ident, fields: &VariantFields, attr: &syn::Attribute) -> syn::Result<()> {
+        let values =
+            attr.parse_args_with(syn::punctuated::Punctuated::<NamedExpr, Token![,]>::parse_terminated)?;
+        let (ranges, values): (Vec<_>, Vec<_>) = values.into_iter().partition(|v| v.name == "from_range");
+
+        // `from_range` replaces the value in `from` functions, so it must be clear which maping it belongs to
+        let from_range = match (ranges.as_slice(), values.len()) {
+            ([], _) => None,
+            ([range], 1) => Some(FromRange::new(&range.value)),
+            ([range], _) => return Err(Error::from_range_single_maping(range.name.span()).into()),
+            ([_, range, ..], _) => return Err(Error::arg_set_twice("from_range", range.name.span()).into()),
+        };
+
+        let mut errors = MultiError::new();
+        for v in values {
+            let name = v.name.to_string();
+            if self.mapings.iter().any(|m| m.name == name) {
+                errors.update(Error::wrong_attribute("mapstr", v.name.span()).into());
+                continue;
+            }
+
+            let maping = match self.value_mapings.iter_mut().find(|m| m.name == name) {
+                Some(m) => m,
+                None => match infer_type(&v.value) {
+                    Some(ty) => {
+                        let maping = ValueMaping::new(name, ty, None, MapingOptions::default());
+                        self.value_mapings.push(maping);
+                        self.value_mapings.last_mut().unwrap()
+                    }
+                    None => {
+                        errors.update(Error::type_not_inferred(&name, v.value.span()).into());
+                        continue;
+                    }
+                },
+            };
+
+            if maping.rules.iter().any(|r| &r.variant == vident) {
+                errors.update(Error::duplicate_maping(&maping.name, v.name.span()).into());
+                continue;
+            }
+            maping.rules.push(ValueRule {
+                variant: vident.clone(),
+                fields: fields.clone(),
+                span: v.value.span(),
+                value: v.value,
+                from_range: from_range.clone(),
+            });
+        }
+        errors.inner
+    }
+
     /// Parse single #[mapstr(..)]
     fn parse_mapstr_attribute(&mut self,
         vident: &Ident,
         mapstr_idx: usize,
-        has_fields: bool,
+        fields: &VariantFields,
+        attr: &syn::Attribute,
+        mapings_on_this_variant: &mut Vec<String>
+    ) -> syn::Result<()> {
+        // `mapstr(<name> = "..", ..)` sets values of multiple existing mapings at once
+        let named_values = attr.parse_args_with(
+            syn::punctuated::Punctuated::<NamedValue, Token![,]>::parse_terminated,
+        );
+        if let Ok(values) = named_values {
+            // Arguments like `name = ".."` also look like named values, anything else must be a maping name
+            let is_named = !values.is_empty()
+                && values.iter().all(|v| !MapStrArgument::EQ_KEYWORDS.iter().any(|kw| v.name == kw));
+            if is_named {
+                let mut errors = MultiError::new();
+                for v in values.iter().filter(|v| !self.mapings.iter().any(|m| v.name == m.name)) {
+                    let name = v.name.to_string();
+                    errors.update(Error::unknown_maping(&name, v.name.span()).into());
+                }
+                if errors.inner.is_err() {
+                    return errors.inner;
+                }
+                for v in values {
+                    let name = syn::LitStr::new(&v.name.to_string(), v.name.span());
+                    let args = MapStrArguments::new(name, v.value);
+                    if let Err(e) = self.add_rule(vident, mapstr_idx, fields, attr, args, mapings_on_this_variant) {
+                        errors.update(e);
+                    }
+                }
+                return errors.inner;
+            }
+        }
+
+        let args = attr.parse_args_with(MapStrArguments::parse)?;
+        self.add_rule(vident, mapstr_idx, fields, attr, args, mapings_on_this_variant)
+    }
+
+    /// Add rule from parsed #[mapstr(..)] to the maping
+    fn add_rule(&mut self,
+        vident: &Ident,
+        mapstr_idx: usize,
+        fields: &VariantFields,
         attr: &syn::Attribute,
+        args: MapStrArguments,
         mapings_on_this_variant: &mut Vec<String>
     ) -> syn::Result<()> {
-        let args = attr
-            .parse_args_with(MapStrArguments::parse)?
-            .finalize(vident);
+        let args = args.finalize(vident, fields);
+        if let Some(name) = &args.name {
+            if self.value_mapings.iter().any(|m| m.name == name.value()) {
+                return Err(Error::wrong_attribute("mapval", name.span()).into());
+            }
+        }
+
+        let parse_template = |lit: &syn::LitStr| -> syn::Result<Template> {
+            let template = Template::parse(&lit.value())
+                .map_err(|msg| syn::Error::from(Error::invalid_template(&msg, lit.span())))?;
+            for field in template.fields() {
+                if !fields.contains(field) {
+                    let field = field.to_string();
+                    return Err(Error::unknown_field(&field, lit.span()).into());
+                }
+            }
+            Ok(template)
+        };
+        let template = parse_template(&args.mapped_value)?;
+        if let Some(FieldValues::Explicit(values)) = &args.fields {
+            for (member, _) in values {
+                let field = FieldRef::from(member);
+                if !fields.contains(&field) {
+                    let field = field.to_string();
+                    return Err(Error::unknown_field(&field, member.span()).into());
+                }
+                if template.fields().any(|f| f == &field) {
+                    let field = field.to_string();
+                    return Err(Error::field_set_twice(&field, member.span()).into());
+                }
+            }
+            for field in fields.refs() {
+                if !template.fields().any(|f| f == &field)
+                    && !values.iter().any(|(m, _)| FieldRef::from(m) == field)
+                {
+                    let field = field.to_string();
+                    return Err(Error::field_not_set(&field, attr.path.span()).into());
+                }
+            }
+        }
+
+        let mut aliases = Vec::with_capacity(args.aliases.len());
+        for lit in &args.aliases {
+            let alias = parse_template(lit)?;
+            // Alias must be able to construct the variant by itself
+            let missing = fields.refs().into_iter().find(|field| {
+                !alias.fields().any(|f| f == field)
+                    && args.fields.as_ref().and_then(|v| v.get(field)).is_none()
+            });
+            if let Some(field) = missing {
+                let field = field.to_string();
+                return Err(Error::field_not_set(&field, lit.span()).into());
+            }
+            aliases.push((alias, lit.span()));
+        }
     
         let maping = if let Some(ref fn_name) = args.name {
             let value = fn_name.value();
             // Found named mapping
-            if let Some(_) = mapings_on_this_variant.iter().find(|&a| a == &value) {
+            if mapings_on_this_variant.iter().any(|a| a == &value) {
                 return Err(Error::duplicate_maping(value.as_str(), fn_name.span()).into())
             } else {
                 mapings_on_this_variant.push(fn_name.value().clone());
@@ -115,279 +566,2714 @@ impl Mapings {
             }
         };
 
-        // Check if to implement Display
-        if let Some(kw) = args.impl_display {
-            if self.is_display_implemented 
-                && match &maping {
-                    Some(maping) => !maping.impl_display,
-                    None => true
-            } {
-                // Some other maping is already implementing display
-                return Err(Error::trait_already_implemented("Display", kw.span()).into());
-            } else {
-                self.is_display_implemented = true;
-            }
-        }
+        // Check that traits are implemented by only one maping
+        let maping_name = match (&maping, &args.name) {
+            (Some(m), _) => m.name.clone(),
+            (None, Some(name)) => name.value(),
+            (None, None) => unreachable!("unnamed maping must already exist"),
+        };
+        Self::claim_traits(&mut self.implemented_traits, &args.options, &maping_name)?;
     
         // Add rule
-        let rule = || {
-            MapingRule {
-                variant: vident.clone(),
-                to: args.mapped_value,
-                has_fields,
+        let rule = MapingRule {
+            variant: vident.clone(),
+            to: template,
+            fields: fields.clone(),
+            values: args.fields.clone(),
+            aliases,
+            primary: args.primary,
+            span: args.mapped_value.span(),
+        };
+
+        let maping = match maping {
+            // Maping fn already present, add new rule to the maping
+            Some(maping) => maping,
+            // First encounter of such maping
+            None => {
+                self.mapings.push(Maping::new(maping_name));
+                self.mapings.last_mut().unwrap()
             }
         };
-        
-        match maping {
-            Some(maping) => {
-                // Maping fn already present, add new rule to the maping
-                maping.rules.push(rule());
-                // Set defaults if unset
-                if maping.default_to.is_none() {
-                    maping.default_to = args.default_to;
-                }
-                if maping.default_from.is_none() {
-                    maping.default_from = args.default_from;
-                }
-                maping.create_to &= args.create_to; // If once set to false, stays false. So if any mapstr sets to=false, function won't be generated
-                maping.create_from &= args.create_from; // Same as above
-                maping.create_try |= args.create_try; // If once set to true, stays true.
-                maping.impl_display |= args.impl_display.is_some();
+        maping.rules.push(rule);
+        maping.update(args.options);
+        Ok(())
+    }
+
+}
+
+/// Fields of a variant
+#[derive(Debug, Clone)]
+enum VariantFields {
+    Unit,
+    Unnamed(usize),
+    Named(Vec<Ident>),
+}
+
+impl VariantFields {
+    fn new(fields: &syn::Fields) -> Self {
+        match fields {
+            syn::Fields::Unit => Self::Unit,
+            syn::Fields::Unnamed(f) => Self::Unnamed(f.unnamed.len()),
+            syn::Fields::Named(f) => Self::Named(
+                f.named
+                    .iter()
+                    .filter_map(|f| f.ident.clone())
+                    .collect(),
+            ),
+        }
+    }
+
+    /// References to all fields in declaration order
+    fn refs(&self) -> Vec<FieldRef> {
+        match self {
+            Self::Unit => Vec::new(),
+            Self::Unnamed(n) => (0..*n).map(FieldRef::Index).collect(),
+            Self::Named(names) => names.iter().map(|n| FieldRef::Named(n.to_string())).collect(),
+        }
+    }
+
+    fn contains(&self, field: &FieldRef) -> bool {
+        match (self, field) {
+            (Self::Unnamed(n), FieldRef::Index(i)) => i < n,
+            (Self::Named(names), FieldRef::Named(name)) => names.iter().any(|n| n == name),
+            _ => false,
+        }
+    }
+
+    /// Expression to construct the variant. Returns `None` if some field doesn't have a value.
+    fn construct(
+        &self,
+        vident: &Ident,
+        value: impl Fn(&FieldRef) -> Option<proc_macro2::TokenStream>,
+    ) -> Option<proc_macro2::TokenStream> {
+        match self {
+            Self::Unit => Some(quote! { #vident }),
+            Self::Unnamed(_) => {
+                let values = self.refs().iter().map(&value).collect::<Option<Vec<_>>>()?;
+                Some(quote! { #vident(#(#values),*) })
             }
-    
-            None => {
-                // First encounter of such maping
-                let rules = if args.is_default {
-                    // We can ignore first maping if it's set as default since we know that defaults are not set and
-                    // we cannot override it with later default.
-                    Vec::new()
-                } else {
-                    vec![rule()]
-                };
-    
-                self.mapings.push(Maping {
-                    name: args.name.unwrap().value(),
-                    rules,
-                    create_to: args.create_to,
-                    create_from: args.create_from,
-                    default_to: args.default_to,
-                    default_from: args.default_from,
-                    create_try: args.create_try,
-                    impl_display: args.impl_display.is_some(),
+            Self::Named(names) => {
+                let values = self.refs().iter().map(&value).collect::<Option<Vec<_>>>()?;
+                Some(quote! { #vident { #(#names: #values),* } })
+            }
+        }
+    }
+}
+
+/// Values for fields that are not parsed from mapped string, set by `fields` argument
+#[derive(Debug, Clone)]
+enum FieldValues {
+    /// `fields = default`
+    Default,
+    /// `fields(0 = expr, name = expr)`
+    Explicit(Vec<(syn::Member, syn::Expr)>),
+}
+
+impl FieldValues {
+    fn get(&self, field: &FieldRef) -> Option<proc_macro2::TokenStream> {
+        match self {
+            Self::Default => Some(quote! { ::std::default::Default::default() }),
+            Self::Explicit(values) => values
+                .iter()
+                .find(|(m, _)| &FieldRef::from(m) == field)
+                .map(|(_, e)| quote! { #e }),
+        }
+    }
+}
+
+impl syn::parse::Parse for FieldValues {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            input.parse::<kw::default>()?;
+            Ok(Self::Default)
+        } else {
+            let content;
+            syn::parenthesized!(content in input);
+            let values = content.parse_terminated::<_, Token![,]>(|input| {
+                let member = input.parse::<syn::Member>()?;
+                input.parse::<Token![=]>()?;
+                Ok((member, input.parse::<syn::Expr>()?))
+            })?;
+            Ok(Self::Explicit(values.into_iter().collect()))
+        }
+    }
+}
+
+/// Single maping from variant to str
+#[derive(Debug, Clone)]
+struct MapingRule {
+    variant: Ident,
+    to: Template,
+    fields: VariantFields,
+    values: Option<FieldValues>,
+    /// Additional values accepted only by `from` functions
+    aliases: Vec<(Template, proc_macro2::Span)>,
+    /// Used by `from` functions if other variants map to the same value
+    primary: bool,
+    span: proc_macro2::Span,
+}
+
+impl MapingRule {
+    /// Binding used for field in patterns
+    fn binding(field: &FieldRef) -> Ident {
+        format_ident!("__field_{}", field.to_string())
+    }
+
+    /// Pattern to match the variant of this rule. Binds fields used in template.
+    fn pattern(&self) -> proc_macro2::TokenStream {
+        self.pattern_for(&quote! { Self })
+    }
+
+    /// Same as [`Self::pattern`] but with given path to the enum instead of `Self`
+    fn pattern_for(&self, enum_path: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let vident = &self.variant;
+        let used: Vec<_> = self.to.fields().collect();
+        match &self.fields {
+            VariantFields::Unit => quote! { #enum_path::#vident },
+            VariantFields::Unnamed(_) if used.is_empty() => quote! { #enum_path::#vident(..) },
+            VariantFields::Unnamed(n) => {
+                let bindings = (0..*n).map(|i| {
+                    let field = FieldRef::Index(i);
+                    if used.contains(&&field) {
+                        let b = Self::binding(&field);
+                        quote! { #b }
+                    } else {
+                        quote! { _ }
+                    }
                 });
+                quote! { #enum_path::#vident(#(#bindings),*) }
+            }
+            VariantFields::Named(names) => {
+                let bindings = names
+                    .iter()
+                    .filter(|n| used.iter().any(|u| matches!(u, FieldRef::Named(u) if n == &u)))
+                    .map(|n| {
+                        let b = Self::binding(&FieldRef::Named(n.to_string()));
+                        quote! { #n: #b, }
+                    });
+                quote! { #enum_path::#vident { #(#bindings)* .. } }
             }
         }
-        Ok(())
     }
 
+    /// Pattern to match the variant of this rule without binding any fields
+    fn wildcard_pattern(&self) -> proc_macro2::TokenStream {
+        let vident = &self.variant;
+        match &self.fields {
+            VariantFields::Unit => quote! { Self::#vident },
+            VariantFields::Unnamed(_) => quote! { Self::#vident(..) },
+            VariantFields::Named(_) => quote! { Self::#vident { .. } },
+        }
+    }
+
+    /// Rules for each alias of this rule
+    fn alias_rules(&self) -> impl Iterator<Item = MapingRule> + '_ {
+        self.aliases.iter().map(|(alias, span)| MapingRule {
+            to: alias.clone(),
+            aliases: Vec::new(),
+            span: *span,
+            ..self.clone()
+        })
+    }
+
+    /// Expression that creates `String` from template
+    fn format(&self) -> proc_macro2::TokenStream {
+        let fmt = self.to.format_str();
+        let args = self.to.fields().map(Self::binding);
+        quote! { ::std::format!(#fmt, #(#args),*) }
+    }
+
+    /// Expression to construct the variant in `from` functions. Fields referenced in template are parsed from
+    /// their bindings, others are taken from `fields` argument. Returns `None` if variant cannot be constructed.
+    fn ctor(&self) -> Option<proc_macro2::TokenStream> {
+        let used: Vec<_> = self.to.fields().collect();
+        if used.iter().enumerate().any(|(i, f)| used[..i].contains(f)) {
+            return None;
+        }
+
+        let ctor = self.fields.construct(&self.variant, |field| {
+            if used.contains(&field) {
+                let b = Self::binding(field);
+                Some(quote! { ::std::str::FromStr::from_str(#b).ok()? })
+            } else {
+                self.values.as_ref().and_then(|v| v.get(field))
+            }
+        })?;
+        Some(quote! { Self::#ctor })
+    }
+
+    /// Rule is used by `from` functions, either as exact match or by parsing the template
+    fn is_from_rule(&self) -> bool {
+        self.ctor().is_some()
+    }
+
+    /// Variant can be constructed by parsing the template
+    fn is_parseable(&self) -> bool {
+        !self.to.is_literal() && self.ctor().is_some()
+    }
+
+    /// Statements that parse `s` according to template. Evaluates to `Option<Self>`.
+    /// Returns `None` if variant cannot be constructed.
+    ///
+    /// `s` is the input before normalization, only literal parts of the template are compared after normalization
+    /// so field values are taken from the input as is. Uses functions from [`Normalization::template_helpers`].
+    fn parser(&self, normalize: &Normalization) -> Option<proc_macro2::TokenStream> {
+        if self.to.is_literal() {
+            return None;
+        }
+        let template = normalize.apply_template(&self.to);
+        let mut segments = template.segments.as_slice();
+        let mut steps = Vec::new();
+        let folds = normalize.folds();
+
+        if let [Segment::Lit(prefix), rest @ ..] = segments {
+            steps.push(if folds {
+                quote! { let s = &s[__match_lit(s, #prefix)?..]; }
+            } else {
+                quote! { let s = s.strip_prefix(#prefix)?; }
+            });
+            segments = rest;
+        }
+        if let [rest @ .., Segment::Lit(suffix)] = segments {
+            steps.push(if folds {
+                quote! { let s = &s[..__match_lit_end(s, #suffix)?]; }
+            } else {
+                quote! { let s = s.strip_suffix(#suffix)?; }
+            });
+            segments = rest;
+        }
+
+        // Remaining segments alternate between fields and literals, starting and ending with a field.
+        // Each field takes everything up to the first occurrence of following literal.
+        for pair in segments.chunks(2) {
+            match pair {
+                [Segment::Field(f), Segment::Lit(sep)] if folds => {
+                    let b = Self::binding(f);
+                    steps.push(quote! {
+                        let (#b, s) = {
+                            let (i, len) = s.char_indices().find_map(|(i, _)| Some((i, __match_lit(&s[i..], #sep)?)))?;
+                            (&s[..i], &s[i + len..])
+                        };
+                    });
+                }
+                [Segment::Field(f), Segment::Lit(sep)] => {
+                    let b = Self::binding(f);
+                    steps.push(quote! {
+                        let (#b, s) = s.split_at(s.find(#sep)?);
+                        let s = &s[#sep.len()..];
+                    });
+                }
+                [Segment::Field(f)] => {
+                    let b = Self::binding(f);
+                    steps.push(quote! { let #b = s; });
+                }
+                _ => unreachable!("template must not have adjacent fields"),
+            }
+        }
+
+        let ctor = self.ctor()?;
+        Some(quote! {
+            #(#steps)*
+            ::std::option::Option::Some(#ctor)
+        })
+    }
+}
+
+/// Values of [`MapingRule`] that are compared to find conflicting rules
+struct RuleKey {
+    variant: String,
+    source: String,
+    /// Skeleton of normalized template, `None` if the rule is not used by `from` functions
+    skeleton: Option<Vec<Option<String>>>,
+}
+
+impl RuleKey {
+    /// Two rules of different variants map to the same value, or to values that `from` functions cannot tell apart
+    fn conflicts(&self, other: &Self) -> bool {
+        self.variant != other.variant && (self.source == other.source || self.conflicts_in_from(other))
+    }
+
+    /// Two rules of different variants cannot be told apart by `from` functions
+    fn conflicts_in_from(&self, other: &Self) -> bool {
+        self.variant != other.variant && self.skeleton.is_some() && self.skeleton == other.skeleton
+    }
+}
+
+/// Normalization applied to strings before matching in `from` functions
+#[derive(Debug, Default, Clone)]
+struct Normalization {
+    trim: bool,
+    ignore_chars: Option<String>,
+    ignore_case: bool,
+    ignore_ascii_case: bool,
+}
+
+impl Normalization {
+    /// Combine options set on different variants, first `ignore_chars` is used
+    fn merge(&mut self, other: Self) {
+        self.trim |= other.trim;
+        self.ignore_case |= other.ignore_case;
+        self.ignore_ascii_case |= other.ignore_ascii_case;
+        if self.ignore_chars.is_none() {
+            self.ignore_chars = other.ignore_chars;
+        }
+    }
+
+    /// Normalize mapped value at compile time, must match [`Self::tokens`]
+    fn apply(&self, s: &str) -> String {
+        let s = if self.trim { s.trim() } else { s };
+        self.apply_chars(s)
+    }
+
+    /// Normalize literal parts of template. Only the start of first and the end of last segment are trimmed.
+    fn apply_template(&self, template: &Template) -> Template {
+        let last = template.segments.len() - 1;
+        template.normalized(|i, l| {
+            let l = match (self.trim, i) {
+                (true, 0) if last == 0 => l.trim(),
+                (true, 0) => l.trim_start(),
+                (true, i) if i == last => l.trim_end(),
+                _ => l,
+            };
+            self.apply_chars(l)
+        })
+    }
+
+    /// Normalization steps after trimming
+    fn apply_chars(&self, s: &str) -> String {
+        let mut s = match &self.ignore_chars {
+            Some(chars) => s.chars().filter(|c| !chars.contains(*c)).collect(),
+            None => String::from(s),
+        };
+        if self.ignore_case {
+            s = s.to_lowercase();
+        } else if self.ignore_ascii_case {
+            s = s.to_ascii_lowercase();
+        }
+        s
+    }
+
+    /// Characters are removed or their case is changed, so normalized literals cannot be compared to the input
+    /// directly
+    fn folds(&self) -> bool {
+        self.ignore_chars.is_some() || self.ignore_case || self.ignore_ascii_case
+    }
+
+    /// Functions that compare normalized literal parts of templates to the input without normalizing it:
+    /// * `__match_lit(s, lit)` - length of the start of `s` that is equal to `lit` after normalization,
+    /// * `__match_lit_end(s, lit)` - start index of the end of `s` that is equal to `lit` after normalization.
+    ///
+    /// Ignored characters around the match are included in it.
+    fn template_helpers(&self) -> proc_macro2::TokenStream {
+        if !self.folds() {
+            return quote! {};
+        }
+
+        let ignored = match &self.ignore_chars {
+            Some(chars) => quote! { #chars.contains(c) },
+            None => quote! { false },
+        };
+        let lower = if self.ignore_case {
+            quote! { c.to_lowercase() }
+        } else if self.ignore_ascii_case {
+            quote! { ::std::iter::once(c.to_ascii_lowercase()) }
+        } else {
+            quote! { ::std::iter::once(c) }
+        };
+        quote! {
+            #[allow(dead_code)]
+            fn __match_lit(s: &str, lit: &str) -> ::std::option::Option<usize> {
+                let mut lit = lit.chars().peekable();
+                for (i, c) in s.char_indices() {
+                    if #ignored {
+                        continue;
+                    }
+                    if lit.peek().is_none() {
+                        return ::std::option::Option::Some(i);
+                    }
+                    for c in #lower {
+                        if lit.next() != ::std::option::Option::Some(c) {
+                            return ::std::option::Option::None;
+                        }
+                    }
+                }
+                lit.peek().is_none().then_some(s.len())
+            }
+
+            #[allow(dead_code)]
+            fn __match_lit_end(s: &str, lit: &str) -> ::std::option::Option<usize> {
+                let mut lit = lit.chars().rev().peekable();
+                for (i, c) in s.char_indices().rev() {
+                    if #ignored {
+                        continue;
+                    }
+                    if lit.peek().is_none() {
+                        return ::std::option::Option::Some(i + c.len_utf8());
+                    }
+                    for c in (#lower).rev() {
+                        if lit.next() != ::std::option::Option::Some(c) {
+                            return ::std::option::Option::None;
+                        }
+                    }
+                }
+                lit.peek().is_none().then_some(0)
+            }
+        }
+    }
+
+    /// Expression that normalizes `s` at runtime. `None` if no normalization is needed.
+    fn tokens(&self) -> Option<proc_macro2::TokenStream> {
+        let mut expr = quote! { s };
+        if self.trim {
+            expr = quote! { #expr.trim() };
+        }
+        if let Some(chars) = &self.ignore_chars {
+            expr = quote! {
+                #expr.chars().filter(|c| !#chars.contains(*c)).collect::<::std::string::String>()
+            };
+        }
+        if self.ignore_case {
+            expr = quote! { #expr.to_lowercase() };
+        } else if self.ignore_ascii_case {
+            expr = quote! { #expr.to_ascii_lowercase() };
+        }
+
+        if self.trim || self.ignore_chars.is_some() || self.ignore_case || self.ignore_ascii_case {
+            Some(expr)
+        } else {
+            None
+        }
+    }
+}
+
+/// One maping with `self.name`
+#[derive(Debug)]
+struct Maping {
+    name: String,
+    rules: Vec<MapingRule>,
+    /// Variants without a rule in this maping
+    unmapped: Vec<Ident>,
+    create_to: bool,
+    create_from: bool,
+    default_to: Option<String>,
+    default_from: Option<proc_macro2::TokenStream>,
+    create_try: bool,
+    normalize: Normalization,
+    allow_duplicate: bool,
+    impl_display: bool,
+    impl_from_str: bool,
+    impl_try_from_str: bool,
+    /// Span of `into_str` keyword, needed for error reporting
+    impl_into_str: Option<proc_macro2::Span>,
+    /// Span of `as_ref` keyword, needed for error reporting
+    impl_as_ref: Option<proc_macro2::Span>,
+    /// Span of `exhaustive` keyword, needed for error reporting
+    exhaustive: Option<proc_macro2::Span>,
+    /// Create `<enum>_<name>!` macro
+    lit_macro: bool,
+    impl_serde: bool,
+    /// Create `<enum>_<name>_serde` module
+    serde_with: bool,
+    /// Span of `clap` keyword, needed for error reporting
+    impl_clap: Option<proc_macro2::Span>,
+    /// Mapped variants in declaration order and their doc comments, collected only for `clap`
+    clap_variants: Vec<(Ident, Option<String>)>,
+    impl_sql: bool,
+    /// Span of `tables` keyword, needed for error reporting
+    tables: Option<proc_macro2::Span>,
+    /// Span of `sql_ddl` keyword, needed for error reporting
+    sql_ddl: Option<proc_macro2::Span>,
+    /// Span of `parse_prefix` keyword, needed for error reporting
+    parse_prefix: Option<proc_macro2::Span>,
+    /// Span of `const_from` keyword, needed for error reporting
+    const_from: Option<proc_macro2::Span>,
+    /// Strategy to find exact matches, chosen by number of values if not set
+    lookup: Option<Lookup>,
+    /// Derive values for variants without a rule from their names
+    rename_all: Option<RenameRule>,
+    /// Default variant from maping declaration
+    default_variant: Option<Box<syn::Expr>>,
+}
+
+impl Maping {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            rules: Vec::new(),
+            unmapped: Vec::new(),
+            create_to: true,
+            create_from: true,
+            default_to: None,
+            default_from: None,
+            create_try: false,
+            normalize: Normalization::default(),
+            allow_duplicate: false,
+            impl_display: false,
+            impl_from_str: false,
+            impl_try_from_str: false,
+            impl_into_str: None,
+            impl_as_ref: None,
+            exhaustive: None,
+            lit_macro: false,
+            impl_serde: false,
+            serde_with: false,
+            impl_clap: None,
+            clap_variants: Vec::new(),
+            impl_sql: false,
+            tables: None,
+            sql_ddl: None,
+            parse_prefix: None,
+            const_from: None,
+            lookup: None,
+            rename_all: None,
+            default_variant: None,
+        }
+    }
+
+    /// Merge options from one #[mapstr(..)] or maping declaration into the maping
+    fn update(&mut self, options: MapingOptions) {
+        // Set defaults if unset
+        if self.default_to.is_none() {
+            self.default_to = options.default_to;
+        }
+        if self.default_from.is_none() {
+            self.default_from = options.default_from;
+        }
+        self.create_to &= !options.no_to; // If once set to false, stays false. So if any mapstr sets to=false, function won't be generated
+        self.create_from &= !options.no_from; // Same as above
+        self.create_try |= options.create_try; // If once set to true, stays true.
+        self.normalize.merge(options.normalize);
+        self.allow_duplicate |= options.allow_duplicate;
+        self.impl_display |= options.impl_display.is_some();
+        self.impl_from_str |= options.impl_from_str.is_some();
+        self.impl_try_from_str |= options.impl_try_from_str.is_some();
+        self.impl_into_str = self.impl_into_str.or(options.impl_into_str.map(|kw| kw.span()));
+        self.impl_as_ref = self.impl_as_ref.or(options.impl_as_ref.map(|kw| kw.span()));
+        self.exhaustive = self.exhaustive.or(options.exhaustive.map(|kw| kw.span()));
+        self.lit_macro |= options.lit_macro;
+        self.impl_serde |= options.impl_serde.is_some();
+        self.serde_with |= options.serde_with;
+        self.impl_clap = self.impl_clap.or(options.impl_clap.map(|kw| kw.span()));
+        self.impl_sql |= options.impl_sql.is_some();
+        self.tables = self.tables.or(options.tables.map(|kw| kw.span()));
+        self.sql_ddl = self.sql_ddl.or(options.sql_ddl.map(|kw| kw.span()));
+        self.parse_prefix = self.parse_prefix.or(options.parse_prefix.map(|kw| kw.span()));
+        self.const_from = self.const_from.or(options.const_from.map(|kw| kw.span()));
+        self.lookup = self.lookup.or(options.lookup);
+    }
+
+    /// Set defaults from `default = Variant` in maping declaration. Must be called after all rules are added.
+    fn resolve_default_variant(&mut self) -> syn::Result<()> {
+        let expr = match self.default_variant.take() {
+            Some(expr) => expr,
+            None => return Ok(()),
+        };
+        let vident = variant_ident(&expr).expect("checked when parsing");
+        let rule = match self.rules.iter().find(|r| &r.variant == vident) {
+            Some(rule) => rule,
+            None => {
+                let vident = vident.to_string();
+                return Err(Error::default_not_mapped(&vident, expr.span()).into());
+            }
+        };
+
+        if self.default_to.is_none() {
+            self.default_to = Some(rule.to.source.clone());
+        }
+        if self.default_from.is_none() {
+            self.default_from = Some(quote! { #expr });
+        }
+        Ok(())
+    }
+
+    /// Add rules from `rename_all` for variants that are not mapped explicitly
+    fn add_renamed_rules(&mut self, variants: &syn::punctuated::Punctuated<syn::Variant, Token![,]>) {
+        let rename_all = match self.rename_all {
+            Some(r) => r,
+            None => return,
+        };
+
+        for variant in variants {
+            if self.rules.iter().any(|r| r.variant == variant.ident) {
+                continue;
+            }
+            let value = rename_all.apply(&variant.ident.to_string());
+            self.rules.push(MapingRule {
+                variant: variant.ident.clone(),
+                to: Template::parse(&value).expect("identifier cannot contain braces"),
+                fields: VariantFields::new(&variant.fields),
+                values: None,
+                aliases: Vec::new(),
+                primary: false,
+                span: variant.ident.span(),
+            });
+        }
+    }
+
+    /// Check that the maping can be expanded
+    fn validate(&self) -> syn::Result<()> {
+        let mut errors = MultiError::new();
+
+        let traits = [("into_str", self.impl_into_str), ("as_ref", self.impl_as_ref), ("sql_ddl", self.sql_ddl)];
+        for (kw, span) in traits {
+            if let Some(span) = span {
+                if self.is_templated() {
+                    errors.update(Error::template_not_allowed(kw, span).into());
+                }
+            }
+        }
+        let n = &self.normalize;
+        for (kw, span) in [("parse_prefix", self.parse_prefix), ("const_from", self.const_from)] {
+            if let (Some(span), true) = (span, n.trim || n.ignore_case || n.ignore_chars.is_some()) {
+                errors.update(Error::normalization_not_supported(kw, span).into());
+            }
+        }
+        if let (Some(span), None, false) = (self.impl_as_ref, &self.default_to, self.is_exhaustive()) {
+            errors.update(Error::arg_not_set("default_to", span).into());
+        }
+        if let (Some(span), false) = (self.exhaustive, self.is_exhaustive()) {
+            let unmapped = self
+                .unmapped
+                .iter()
+                .map(|v| format!("`{v}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            errors.update(Error::not_exhaustive(&unmapped, span).into());
+        }
+
+        let rules = self.all_parse_rules();
+        let keys = self.rule_keys(&rules);
+        for (i, r) in rules.iter().enumerate() {
+            let Some(other) = (0..i).find(|&j| keys[j].conflicts(&keys[i])).map(|j| &rules[j]) else {
+                continue;
+            };
+            let other_variant = other.variant.to_string();
+            let variant = r.variant.to_string();
+            if self.allow_duplicate {
+                continue;
+            } else if other.to.source == r.to.source {
+                // Point at both attributes
+                errors.update(Error::duplicate_value(&other_variant, r.span).into());
+                errors.update(Error::duplicate_value(&variant, other.span).into());
+            } else if r.to.is_literal() {
+                let other = other.to.literal();
+                errors.update(Error::same_after_normalization(&other, r.span).into());
+            } else {
+                errors.update(Error::ambiguous_template(&other_variant, r.span).into());
+            }
+        }
+
+        if !self.create_from {
+            return errors.inner;
+        }
+
+        // Each group of values that cannot be told apart must have exactly one primary variant
+        if self.allow_duplicate {
+            for (i, r) in rules.iter().enumerate() {
+                if keys[i].skeleton.is_none() || (0..i).any(|j| keys[j].conflicts_in_from(&keys[i])) {
+                    continue;
+                }
+                let group: Vec<_> = (i..rules.len())
+                    .filter(|&j| keys[j].variant == keys[i].variant || keys[j].conflicts_in_from(&keys[i]))
+                    .map(|j| &rules[j])
+                    .collect();
+                if group.iter().all(|o| o.variant == r.variant) {
+                    continue;
+                }
+                let mut primaries = group.iter().filter(|o| o.primary);
+                match primaries.next() {
+                    None => {
+                        let other = group.iter().find(|o| o.variant != r.variant).unwrap();
+                        let other = other.variant.to_string();
+                        errors.update(Error::primary_not_set(&other, r.span).into());
+                    }
+                    Some(first) => {
+                        if let Some(second) = primaries.find(|o| o.variant != first.variant) {
+                            let other = first.variant.to_string();
+                            errors.update(Error::primary_set_twice(&other, second.span).into());
+                        }
+                    }
+                }
+            }
+        }
+
+        for r in rules.iter().filter(|r| r.is_parseable()) {
+            if self.normalize.apply_template(&r.to).has_adjacent_fields() {
+                errors.update(Error::adjacent_fields(r.span).into());
+            }
+        }
+
+        errors.inner
+    }
+
+    fn expand(
+        &self,
+        eident: &syn::Ident,
+        evis: &syn::Visibility,
+        egenerics: &syn::Generics,
+    ) -> proc_macro2::TokenStream {
+        let to = self.create_to(evis);
+        let from = self.create_from(evis);
+        let from_const = self.create_from_const(evis);
+        let parse_prefix = self.create_parse_prefix(evis);
+        let aliases = self.create_aliases(evis);
+        let tables = self.create_tables(evis);
+        let sql_ddl = self.create_sql_ddl(eident, evis);
+
+        let display = self.create_display(eident, egenerics);
+        let from_str = self.create_from_str(eident, egenerics);
+        let conversions = self.create_conversions(eident, egenerics);
+        let lit_macro = self.create_lit_macro(eident);
+        let serde = self.create_serde(eident, egenerics);
+        let serde_fns = self.create_serde_fns(evis);
+        let serde_module = self.create_serde_module(eident, evis, egenerics);
+        let clap = self.create_clap(eident, egenerics);
+        let sql = self.create_sql(eident, egenerics);
+
+        let (impl_generics, ty_generics, where_clause) = egenerics.split_for_impl();
+        quote! {
+            impl #impl_generics #eident #ty_generics #where_clause {
+                #to
+                #from
+                #from_const
+                #parse_prefix
+                #aliases
+                #tables
+                #sql_ddl
+                #serde_fns
+            }
+
+            #display
+            #from_str
+            #conversions
+            #lit_macro
+            #serde
+            #serde_module
+            #clap
+            #sql
+        }
+    }
+
+    /// All rules including aliases, aliases follow their canonical value
+    fn all_parse_rules(&self) -> Vec<MapingRule> {
+        self.rules
+            .iter()
+            .flat_map(|r| std::iter::once(r.clone()).chain(r.alias_rules()))
+            .collect()
+    }
+
+    /// Rules used by `from` functions. If duplicates are allowed, rules shadowed by a `primary` rule are removed.
+    fn parse_rules(&self) -> Vec<MapingRule> {
+        let rules = self.all_parse_rules();
+        if !self.allow_duplicate {
+            return rules;
+        }
+        let keys = self.rule_keys(&rules);
+        let primaries: Vec<_> = keys.iter().zip(&rules).filter(|(_, r)| r.primary).map(|(k, _)| k).collect();
+        rules
+            .iter()
+            .zip(&keys)
+            .filter(|(r, k)| r.primary || !primaries.iter().any(|o| o.conflicts_in_from(k)))
+            .map(|(r, _)| r.clone())
+            .collect()
+    }
+
+    /// Values to compare the rules with, computed once per rule
+    fn rule_keys(&self, rules: &[MapingRule]) -> Vec<RuleKey> {
+        rules
+            .iter()
+            .map(|r| RuleKey {
+                variant: r.variant.to_string(),
+                source: r.to.source.clone(),
+                skeleton: (self.create_from && r.is_from_rule()).then(|| {
+                    self.normalize
+                        .apply_template(&r.to)
+                        .skeleton()
+                        .into_iter()
+                        .map(|l| l.map(String::from))
+                        .collect()
+                }),
+            })
+            .collect()
+    }
+
+    /// Every variant is mapped, `to` functions cannot fail
+    fn is_exhaustive(&self) -> bool {
+        self.unmapped.is_empty()
+    }
+
+    /// Some of the rules use field values. `to` functions must return `Cow<'static, str>` instead of `&'static str`.
+    fn is_templated(&self) -> bool {
+        self.rules.iter().any(|r| !r.to.is_literal())
+    }
+
+    /// Literal value returned from `to` functions
+    fn to_value(&self, s: &str) -> proc_macro2::TokenStream {
+        if self.is_templated() {
+            quote! { ::std::borrow::Cow::Borrowed(#s) }
+        } else {
+            quote! { #s }
+        }
+    }
+
+    /// Expression that matches `scrutinee` against the maping. Patterns start with `enum_path`.
+    /// `found` wraps the mapped value, `fallback` is the value for unmapped variants. If the maping is exhaustive,
+    /// `fallback` is not used.
+    fn to_body(
+        &self,
+        scrutinee: proc_macro2::TokenStream,
+        enum_path: proc_macro2::TokenStream,
+        found: impl Fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream,
+        fallback: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let arms = self.rules.iter().map(|r| {
+            let v = if r.to.is_literal() {
+                self.to_value(&r.to.literal())
+            } else {
+                let f = r.format();
+                quote! { ::std::borrow::Cow::Owned(#f) }
+            };
+            let pattern = r.pattern_for(&enum_path);
+            let v = found(v);
+            quote! { #pattern => #v, }
+        });
+
+        let fallback = (!self.is_exhaustive()).then(|| quote! { _ => #fallback });
+        quote! {
+            match #scrutinee {
+                #(#arms)*
+                #fallback
+            }
+        }
+    }
+
+    /// Create [try]_to function TokenStreams
+    fn create_to(&self, enum_vis: &syn::Visibility) -> proc_macro2::TokenStream {
+        if !self.create_to {
+            return quote! {};
+        }
+
+        let ret_ty = if self.is_templated() {
+            quote! { ::std::borrow::Cow<'static, str> }
+        } else {
+            quote! { &'static str }
+        };
+        // Formatting templates is not possible in const context
+        let constness = (!self.is_templated()).then(|| quote! { const });
+
+        let to = |def_to: &str| {
+            let to_fn_name = format_ident!("to_{}", self.name);
+            let def_to = self.to_value(def_to);
+            let body = self.to_body(quote! { self }, quote! { Self }, |v| v, def_to);
+            quote! {
+                #enum_vis #constness fn #to_fn_name(&self) -> #ret_ty {
+                    #body
+                }
+            }
+        };
+
+        let try_to = || {
+            let to_fn_name = format_ident!("try_to_{}", self.name);
+            let body = self.to_body(
+                quote! { self },
+                quote! { Self },
+                |v| quote! { ::std::option::Option::Some(#v) },
+                quote! { ::std::option::Option::None },
+            );
+            quote! {
+                #enum_vis #constness fn #to_fn_name(&self) -> ::std::option::Option<#ret_ty> {
+                    #body
+                }
+            }
+        };
+
+        match (&self.default_to, &self.create_try) {
+            // Default is never used, `try_to` is still created if it would be without every variant mapped
+            _ if self.is_exhaustive() => {
+                let to = to("");
+                let try_to = if self.create_try || self.default_to.is_none() { try_to() } else { quote! {} };
+                quote! {
+                    #to
+                    #try_to
+                }
+            }
+            (Some(def_to), false) => to(def_to),
+            (Some(def_to), true) => {
+                let to = to(def_to);
+                let try_to = try_to();
+                quote! {
+                    #to
+                    #try_to
+                }
+            }
+            (None, _) => try_to(),
+        }
+    }
+
+    /// Expression that matches `s` against the maping.
+    /// `found` wraps the constructed variant, `fallback` is the value if nothing matches.
+    fn parse_body(
+        &self,
+        found: impl Fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream,
+        fallback: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let rules = self.parse_rules();
+        let mut v_ctors = Vec::new();
+        let mut v_strs = Vec::new();
+        for r in rules.iter().filter(|r| r.to.is_literal()) {
+            let (ctor, s) = match r.ctor() {
+                Some(ctor) => (found(ctor), self.normalize.apply(&r.to.literal())),
+                None => continue,
+            };
+            // First one wins like in a chain of comparisons
+            if !v_strs.contains(&s) {
+                v_ctors.push(ctor);
+                v_strs.push(s);
+            }
+        }
+
+        // Templates are tried in declaration order after all exact matches. They parse the input before
+        // normalization, so that field values are not changed.
+        let parsers: Vec<_> = rules.iter().filter_map(|r| r.parser(&self.normalize)).collect();
+        let found_parsed = found(quote! { v });
+        let raw = if self.normalize.trim { quote! { s.trim() } } else { quote! { s } };
+        let helpers = if parsers.is_empty() { quote! {} } else { self.normalize.template_helpers() };
+
+        // Original input is still available as `s` in fallback
+        let (normalize, scrutinee) = match self.normalize.tokens() {
+            Some(n) => (quote! { let __normalized = #n; }, quote! { &*__normalized }),
+            None => (quote! {}, quote! { s }),
+        };
+
+        let parse_templates = quote! {
+            #helpers
+            #(
+                if let ::std::option::Option::Some(v) = (|s: &str| { #parsers })(#raw) {
+                    return #found_parsed;
+                }
+            )*
+            #fallback
+        };
+
+        match self.lookup.unwrap_or_else(|| Lookup::default_for(v_strs.len())) {
+            Lookup::Match => quote! {
+                #normalize
+                match #scrutinee {
+                    #(#v_strs => #v_ctors,)*
+                    __s => {
+                        #parse_templates
+                    }
+                }
+            },
+            lookup => {
+                let index = lookup.index(&quote! { __s }, &v_strs);
+                let idx = 0..v_strs.len();
+                quote! {
+                    #normalize
+                    let __s: &str = #scrutinee;
+                    match #index {
+                        #(::std::option::Option::Some(#idx) => #v_ctors,)*
+                        _ => {
+                            #parse_templates
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Create [try_]from functions TokenStreams.
+    fn create_from(&self, enum_vis: &syn::Visibility) -> proc_macro2::TokenStream {
+        if !self.create_from {
+            return quote! {};
+        }
+
+        let from = |def_from| {
+            let from_fn_name = format_ident!("from_{}", self.name);
+            let body = self.parse_body(|v| v, quote! { Self::#def_from });
+            quote! {
+                #enum_vis fn #from_fn_name(s: &str) -> Self {
+                    #body
+                }
+            }
+        };
+
+        let try_from = || {
+            let from_fn_name = format_ident!("try_from_{}", self.name);
+            let body = self.parse_body(
+                |v| quote! { ::std::option::Option::Some(#v) },
+                quote! { ::std::option::Option::None },
+            );
+            quote! {
+                #enum_vis fn #from_fn_name(s: &str) -> ::std::option::Option<Self> {
+                    #body
+                }
+            }
+        };
+
+        match (&self.default_from, &self.create_try) {
+            (Some(def_from), false) => from(def_from),
+            (Some(def_from), true) => {
+                let from = from(def_from);
+                let try_from = try_from();
+                quote! {
+                    #from
+                    #try_from
+                }
+            }
+            (None, _) => try_from(),
+        }
+    }
+
+    /// Create `try_from_<name>_const` function that matches literal values of unit variants with byte-wise
+    /// comparison. Normalization other than `ignore_ascii_case` is rejected in `validate`.
+    fn create_from_const(&self, enum_vis: &syn::Visibility) -> proc_macro2::TokenStream {
+        let n = &self.normalize;
+        if self.const_from.is_none() || !self.create_from {
+            return quote! {};
+        }
+
+        let (values, ctors): (Vec<_>, Vec<_>) = self
+            .parse_rules()
+            .iter()
+            .filter(|r| r.to.is_literal() && matches!(r.fields, VariantFields::Unit))
+            .filter_map(|r| Some((r.to.literal(), r.ctor()?)))
+            .unzip();
+        let byte_eq = if n.ignore_ascii_case {
+            quote! { a[i].eq_ignore_ascii_case(&b[i]) }
+        } else {
+            quote! { a[i] == b[i] }
+        };
+
+        let fn_name = format_ident!("try_from_{}_const", self.name);
+        quote! {
+            #enum_vis const fn #fn_name(s: &str) -> ::std::option::Option<Self> {
+                const fn eq(a: &[u8], b: &[u8]) -> bool {
+                    if a.len() != b.len() {
+                        return false;
+                    }
+                    let mut i = 0;
+                    while i < a.len() {
+                        if !(#byte_eq) {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+
+                let s = s.as_bytes();
+                #(
+                    if eq(s, #values.as_bytes()) {
+                        return ::std::option::Option::Some(#ctors);
+                    }
+                )*
+                ::std::option::Option::None
+            }
+        }
+    }
+
+    /// Create `parse_prefix_<name>` function that finds the longest literal value at the start of the input with a
+    /// trie. Normalization other than `ignore_ascii_case` is rejected in `validate`.
+    fn create_parse_prefix(&self, enum_vis: &syn::Visibility) -> proc_macro2::TokenStream {
+        let n = &self.normalize;
+        if self.parse_prefix.is_none() || !self.create_from {
+            return quote! {};
+        }
+
+        let mut ctors = Vec::new();
+        let mut keys = Vec::new();
+        for r in self.parse_rules().iter().filter(|r| r.to.is_literal()) {
+            let (ctor, key) = match r.ctor() {
+                Some(ctor) => (ctor, n.apply(&r.to.literal())),
+                None => continue,
+            };
+            if !keys.contains(&key) {
+                ctors.push(ctor);
+                keys.push(key);
+            }
+        }
+
+        let longest = lookup::longest_prefix(&quote! { s }, &keys, n.ignore_ascii_case);
+        let idx = 0..keys.len();
+        let fn_name = format_ident!("parse_prefix_{}", self.name);
+        quote! {
+            #enum_vis fn #fn_name(s: &str) -> ::std::option::Option<(Self, &str)> {
+                match #longest {
+                    #(::std::option::Option::Some((#idx, len)) => ::std::option::Option::Some((#ctors, &s[len..])),)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    }
+
+    /// Create `<enum>_<name>!("..")` macro that expands to the variant with given literal value and fails to compile
+    /// on unknown values. The macro is re-exported, so it can be called by path from other modules of the crate.
+    fn create_lit_macro(&self, eident: &Ident) -> proc_macro2::TokenStream {
+        if !self.lit_macro {
+            return quote! {};
+        }
+
+        let macro_name = format_ident!("{}_{}", RenameRule::Snake.apply(&eident.to_string()), self.name);
+        let (values, ctors): (Vec<_>, Vec<_>) = self
+            .parse_rules()
+            .iter()
+            .filter(|r| r.to.is_literal())
+            .filter_map(|r| {
+                let ctor = r.fields.construct(&r.variant, |field| r.values.as_ref().and_then(|v| v.get(field)))?;
+                Some((r.to.literal(), quote! { #eident::#ctor }))
+            })
+            .unzip();
+        let expected = values
+            .iter()
+            .map(|v| format!("`{v}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let unknown = format!("unknown `{}` value ", self.name);
+        let expected = format!(", expected one of: {expected}");
+
+        quote! {
+            #[allow(unused_macros)]
+            macro_rules! #macro_name {
+                #((#values) => { #ctors };)*
+                ($other:tt) => {
+                    ::std::compile_error!(::std::concat!(#unknown, ::std::stringify!($other), #expected))
+                };
+            }
+
+            #[allow(unused_imports)]
+            pub(crate) use #macro_name;
+        }
+    }
+
+    /// Create `<name>_aliases` function if any of the rules have aliases
+    fn create_aliases(&self, enum_vis: &syn::Visibility) -> proc_macro2::TokenStream {
+        if self.rules.iter().all(|r| r.aliases.is_empty()) {
+            return quote! {};
+        }
+
+        let fn_name = format_ident!("{}_aliases", self.name);
+        let (patterns, aliases): (Vec<_>, Vec<_>) = self
+            .rules
+            .iter()
+            .filter(|r| !r.aliases.is_empty())
+            .map(|r| {
+                let aliases = r.aliases.iter().map(|(a, _)| a.source.as_str());
+                (r.wildcard_pattern(), quote! { #(#aliases),* })
+            })
+            .unzip();
+        quote! {
+            #enum_vis fn #fn_name(&self) -> &'static [&'static str] {
+                match self {
+                    #(#patterns => &[#aliases],)*
+                    _ => &[],
+                }
+            }
+        }
+    }
+
+    /// Create `<NAME>_STRS` constant with all literal values and `<name>_pairs` function that iterates over
+    /// variants that can be created from literal values
+    fn create_tables(&self, enum_vis: &syn::Visibility) -> proc_macro2::TokenStream {
+        if self.tables.is_none() {
+            return quote! {};
+        }
+
+        let strs_name = format_ident!("{}_STRS", self.name.to_uppercase());
+        let pairs_name = format_ident!("{}_pairs", self.name);
+        let doc = format!("All literal values of `{}` maping", self.name);
+        let pairs_doc = format!("Variants and their values in `{}` maping", self.name);
+
+        let mut strs: Vec<String> = Vec::new();
+        for r in self.rules.iter().filter(|r| r.to.is_literal()) {
+            let s = r.to.literal();
+            if !strs.contains(&s) {
+                strs.push(s);
+            }
+        }
+
+        let (ctors, values): (Vec<_>, Vec<_>) = self
+            .rules
+            .iter()
+            .filter(|r| r.to.is_literal())
+            .filter_map(|r| Some((r.ctor()?, r.to.literal())))
+            .unzip();
+        let len = ctors.len();
+
+        quote! {
+            #[doc = #doc]
+            #enum_vis const #strs_name: &'static [&'static str] = &[#(#strs),*];
+
+            #[doc = #pairs_doc]
+            #enum_vis fn #pairs_name() -> impl ::std::iter::Iterator<Item = (Self, &'static str)> {
+                let pairs: [(Self, &'static str); #len] = [#((#ctors, #values)),*];
+                ::std::iter::IntoIterator::into_iter(pairs)
+            }
+        }
+    }
+
+    /// Create `<NAME>_SQL_CHECK` and `<NAME>_PG_ENUM_DDL` constants and `fn <name>_sql_check(column)` with every
+    /// value that `to` functions can return
+    fn create_sql_ddl(&self, eident: &Ident, enum_vis: &syn::Visibility) -> proc_macro2::TokenStream {
+        if self.sql_ddl.is_none() || self.is_templated() {
+            return quote! {};
+        }
+
+        let mut values: Vec<String> = Vec::new();
+        let literals = self.rules.iter().map(|r| r.to.literal());
+        for v in literals.chain(self.default_to.clone()) {
+            if !values.contains(&v) {
+                values.push(v);
+            }
+        }
+        let values = values.iter().map(|v| sql_string(v)).collect::<Vec<_>>().join(", ");
+
+        let check_name = format_ident!("{}_SQL_CHECK", self.name.to_uppercase());
+        let check_fn_name = format_ident!("{}_sql_check", self.name);
+        let ddl_name = format_ident!("{}_PG_ENUM_DDL", self.name.to_uppercase());
+        let check = format!("CHECK ({} IN ({values}))", sql_ident(&self.name));
+        let type_name = format!("{}_{}", RenameRule::Snake.apply(&eident.to_string()), self.name);
+        let ddl = format!("CREATE TYPE {} AS ENUM ({values})", sql_ident(&type_name));
+        let name = &self.name;
+        let check_doc = format!("SQL check constraint that allows only values of `{name}` maping in column `{name}`");
+        let check_fn_doc = format!("SQL check constraint that allows only values of `{name}` maping in given column");
+        let ddl_doc = format!("PostgreSQL statement to create enum type `{type_name}` with values of `{name}` maping");
+        quote! {
+            #[doc = #check_doc]
+            #enum_vis const #check_name: &'static str = #check;
+
+            #[doc = #check_fn_doc]
+            #enum_vis fn #check_fn_name(column: &str) -> ::std::string::String {
+                ::std::format!("CHECK (\"{}\" IN ({}))", column.replace('"', "\"\""), #values)
+            }
+
+            #[doc = #ddl_doc]
+            #enum_vis const #ddl_name: &'static str = #ddl;
+        }
+    }
+
+    /// Mapped values that can be parsed by `from` functions, templates are given as written
+    fn expected(&self) -> Vec<String> {
+        self.parse_rules()
+            .iter()
+            .filter(|r| r.ctor().is_some())
+            .map(|r| {
+                if r.to.is_literal() {
+                    r.to.literal()
+                } else {
+                    r.to.source.clone()
+                }
+            })
+            .collect()
+    }
+
+    /// Create impl block for FromStr trait and its error type `Parse<Enum>Error`
+    fn create_from_str(&self, eident: &Ident, egenerics: &syn::Generics) -> proc_macro2::TokenStream {
+        if !self.impl_from_str {
+            return quote! {};
+        }
+
+        let error_ident = parse_error_ident(eident);
+        let body = self.parse_result_body(eident);
+        let (impl_generics, ty_generics, where_clause) = egenerics.split_for_impl();
+        quote! {
+            impl #impl_generics ::std::str::FromStr for #eident #ty_generics #where_clause {
+                type Err = #error_ident;
+
+                fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                    #body
+                }
+            }
+        }
+    }
+
+    /// Expression that parses `s` into `Result<Self, Parse<Enum>Error>`
+    fn parse_result_body(&self, eident: &Ident) -> proc_macro2::TokenStream {
+        let error_ident = parse_error_ident(eident);
+        let name = &self.name;
+        let expected = self.expected();
+        let fallback = match &self.default_from {
+            Some(def_from) => quote! { ::std::result::Result::Ok(Self::#def_from) },
+            None => quote! {
+                ::std::result::Result::Err(#error_ident {
+                    input: ::std::string::String::from(s),
+                    mapping: #name,
+                    expected: &[#(#expected),*],
+                })
+            },
+        };
+        self.parse_body(|v| quote! { ::std::result::Result::Ok(#v) }, fallback)
+    }
+
+    /// Maping uses `Parse<Enum>Error` type
+    fn uses_parse_error(&self) -> bool {
+        self.impl_from_str || ((self.impl_try_from_str || self.impl_sql) && self.default_from.is_none())
+    }
+
+    /// Create impl blocks for `[Try]From<&str> for Self`, `[Try]From<Self> for &'static str` and `AsRef<str>`
+    fn create_conversions(&self, eident: &Ident, egenerics: &syn::Generics) -> proc_macro2::TokenStream {
+        let (impl_generics, ty_generics, where_clause) = egenerics.split_for_impl();
+
+        let try_from_str = match (self.impl_try_from_str, &self.default_from) {
+            (false, _) => quote! {},
+            (true, Some(def_from)) => {
+                let body = self.parse_body(|v| v, quote! { Self::#def_from });
+                quote! {
+                    impl #impl_generics ::std::convert::From<&str> for #eident #ty_generics #where_clause {
+                        fn from(s: &str) -> Self {
+                            #body
+                        }
+                    }
+                }
+            }
+            (true, None) => {
+                let error_ident = parse_error_ident(eident);
+                let body = self.parse_result_body(eident);
+                quote! {
+                    impl #impl_generics ::std::convert::TryFrom<&str> for #eident #ty_generics #where_clause {
+                        type Error = #error_ident;
+
+                        fn try_from(s: &str) -> ::std::result::Result<Self, Self::Error> {
+                            #body
+                        }
+                    }
+                }
+            }
+        };
+
+        let def_to = self.default_to.as_deref().or(self.is_exhaustive().then_some(""));
+        let into_str = match (self.impl_into_str, def_to) {
+            (None, _) => quote! {},
+            (Some(_), Some(def_to)) => {
+                let body = self.to_body(quote! { v }, quote! { #eident }, |v| v, quote! { #def_to });
+                quote! {
+                    impl #impl_generics ::std::convert::From<#eident #ty_generics> for &'static str #where_clause {
+                        fn from(v: #eident #ty_generics) -> Self {
+                            #body
+                        }
+                    }
+                }
+            }
+            (Some(_), None) => {
+                let body = self.to_body(
+                    quote! { &v },
+                    quote! { #eident },
+                    |v| quote! { ::std::result::Result::Ok(#v) },
+                    quote! { ::std::result::Result::Err(v) },
+                );
+                quote! {
+                    impl #impl_generics ::std::convert::TryFrom<#eident #ty_generics> for &'static str #where_clause {
+                        type Error = #eident #ty_generics;
+
+                        fn try_from(v: #eident #ty_generics) -> ::std::result::Result<Self, Self::Error> {
+                            #body
+                        }
+                    }
+                }
+            }
+        };
+
+        let as_ref = match (self.impl_as_ref, def_to) {
+            (Some(_), Some(def_to)) => {
+                let body = self.to_body(quote! { self }, quote! { Self }, |v| v, quote! { #def_to });
+                quote! {
+                    impl #impl_generics ::std::convert::AsRef<str> for #eident #ty_generics #where_clause {
+                        fn as_ref(&self) -> &str {
+                            #body
+                        }
+                    }
+                }
+            }
+            // Error is reported in `validate`
+            _ => quote! {},
+        };
+
+        quote! {
+            #try_from_str
+            #into_str
+            #as_ref
+        }
+    }
+
+    /// Create impl block for Display trait
+    fn create_display(&self, eident: &Ident, egenerics: &syn::Generics) -> proc_macro2::TokenStream {
+        if !self.impl_display {
+            return quote! {};
+        }
+
+        let def = match &self.default_to {
+            _ if self.is_exhaustive() => quote! {},
+            Some(def) => quote! { _ => f.write_str(#def) },
+            None => quote! { _ => f.write_str("Unknown variant") },
+        };
+
+        let arms = self.rules.iter().map(|r| {
+            let pattern = r.pattern();
+            if r.to.is_literal() {
+                let s = r.to.literal();
+                quote! { #pattern => f.write_str(#s) }
+            } else {
+                let fmt = r.to.format_str();
+                let args = r.to.fields().map(MapingRule::binding);
+                quote! { #pattern => ::std::write!(f, #fmt, #(#args),*) }
+            }
+        });
+
+        let (impl_generics, ty_generics, where_clause) = egenerics.split_for_impl();
+        quote! {
+            impl #impl_generics ::std::fmt::Display for #eident #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match self {
+                        #(#arms,)*
+                        #def
+                    }
+                }
+            }
+        }
+    }
+
+    /// Create impl blocks for `serde::Serialize` and `serde::Deserialize`. Unknown strings are deserialized to
+    /// `default_from` if it's set.
+    fn create_serde(&self, eident: &Ident, egenerics: &syn::Generics) -> proc_macro2::TokenStream {
+        if !self.impl_serde {
+            return quote! {};
+        }
+
+        let serialize = self.serialize_body();
+        let deserialize = self.deserialize_body();
+        let mut de_generics = egenerics.clone();
+        de_generics.params.insert(0, syn::parse_quote! { 'de });
+        let (de_impl_generics, _, _) = de_generics.split_for_impl();
+        let (impl_generics, ty_generics, where_clause) = egenerics.split_for_impl();
+        quote! {
+            impl #impl_generics ::serde::Serialize for #eident #ty_generics #where_clause {
+                fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                where
+                    S: ::serde::Serializer,
+                {
+                    #serialize
+                }
+            }
+
+            impl #de_impl_generics ::serde::Deserialize<'de> for #eident #ty_generics #where_clause {
+                fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                where
+                    D: ::serde::Deserializer<'de>,
+                {
+                    #deserialize
+                }
+            }
+        }
+    }
+
+    /// Body of `serialize` function, serializes `self` with `serializer: S`
+    fn serialize_body(&self) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        let def = match &self.default_to {
+            _ if self.is_exhaustive() => quote! {},
+            Some(def) => quote! { _ => serializer.serialize_str(#def) },
+            None => {
+                let msg = format!("variant is not mapped in maping `{name}`");
+                quote! { _ => ::std::result::Result::Err(<S::Error as ::serde::ser::Error>::custom(#msg)) }
+            }
+        };
+        let arms = self.rules.iter().map(|r| {
+            let pattern = r.pattern();
+            if r.to.is_literal() {
+                let s = r.to.literal();
+                quote! { #pattern => serializer.serialize_str(#s) }
+            } else {
+                let fmt = r.to.format_str();
+                let args = r.to.fields().map(MapingRule::binding);
+                quote! { #pattern => serializer.collect_str(&::std::format_args!(#fmt, #(#args),*)) }
+            }
+        });
+
+        quote! {
+            match self {
+                #(#arms,)*
+                #def
+            }
+        }
+    }
+
+    /// Body of `deserialize` function, deserializes `Self` from `deserializer: D`
+    fn deserialize_body(&self) -> proc_macro2::TokenStream {
+        let expected = self.expected();
+        let fallback = match &self.default_from {
+            Some(def_from) => quote! { ::std::result::Result::Ok(Self::#def_from) },
+            None => quote! {
+                ::std::result::Result::Err(<D::Error as ::serde::de::Error>::unknown_variant(s, &[#(#expected),*]))
+            },
+        };
+        let body = self.parse_body(|v| quote! { ::std::result::Result::Ok(#v) }, fallback);
+
+        quote! {
+            let s = <::std::string::String as ::serde::Deserialize>::deserialize(deserializer)?;
+            let s = s.as_str();
+            #body
+        }
+    }
+
+    /// Create hidden `__serialize_<name>` and `__deserialize_<name>` functions used by the `serde_with` module
+    fn create_serde_fns(&self, enum_vis: &syn::Visibility) -> proc_macro2::TokenStream {
+        if !self.serde_with {
+            return quote! {};
+        }
+
+        let serialize = self.serialize_body();
+        let deserialize = self.deserialize_body();
+        let ser_name = format_ident!("__serialize_{}", self.name);
+        let de_name = format_ident!("__deserialize_{}", self.name);
+        quote! {
+            #[doc(hidden)]
+            #enum_vis fn #ser_name<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                #serialize
+            }
+
+            #[doc(hidden)]
+            #enum_vis fn #de_name<'de, D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                #deserialize
+            }
+        }
+    }
+
+    /// Create module `<enum>_<name>_serde` to be used with `#[serde(with = "..")]`. It contains `serialize` and
+    /// `deserialize` for the enum and submodules `option` and `vec` for `Option<Enum>` and `Vec<Enum>`.
+    fn create_serde_module(
+        &self,
+        eident: &Ident,
+        evis: &syn::Visibility,
+        egenerics: &syn::Generics,
+    ) -> proc_macro2::TokenStream {
+        if !self.serde_with {
+            return quote! {};
+        }
+
+        let mod_name = format_ident!("{}_{}_serde", RenameRule::Snake.apply(&eident.to_string()), self.name);
+        let ser_name = format_ident!("__serialize_{}", self.name);
+        let de_name = format_ident!("__deserialize_{}", self.name);
+        let doc = format!(
+            "Serialize [`{eident}`] with maping `{}`, use with `#[serde(with = \"{mod_name}\")]`",
+            self.name
+        );
+
+        let with_param = |param: syn::GenericParam| {
+            let mut generics = egenerics.clone();
+            match param {
+                syn::GenericParam::Lifetime(_) => generics.params.insert(0, param),
+                _ => generics.params.push(param),
+            }
+            generics
+        };
+        let ser_generics = with_param(syn::parse_quote! { S: ::serde::Serializer });
+        let mut de_generics = with_param(syn::parse_quote! { D: ::serde::Deserializer<'de> });
+        de_generics.params.insert(0, syn::parse_quote! { 'de });
+        let wrapper_generics = with_param(syn::parse_quote! { '__a });
+        let (ser_generics, _, ser_where) = ser_generics.split_for_impl();
+        let (de_generics, _, de_where) = de_generics.split_for_impl();
+        let (wrapper_impl_generics, wrapper_ty_generics, _) = wrapper_generics.split_for_impl();
+        let (impl_generics, ty_generics, where_clause) = egenerics.split_for_impl();
+        let mut de_impl_generics = egenerics.clone();
+        de_impl_generics.params.insert(0, syn::parse_quote! { 'de });
+        let (de_impl_generics, _, _) = de_impl_generics.split_for_impl();
+
+        quote! {
+            #[doc = #doc]
+            #[allow(dead_code)]
+            #evis mod #mod_name {
+                use super::*;
+
+                pub fn serialize #ser_generics(
+                    value: &#eident #ty_generics,
+                    serializer: S,
+                ) -> ::std::result::Result<S::Ok, S::Error> #ser_where {
+                    #eident::#ser_name(value, serializer)
+                }
+
+                pub fn deserialize #de_generics(
+                    deserializer: D,
+                ) -> ::std::result::Result<#eident #ty_generics, D::Error> #de_where {
+                    #eident::#de_name(deserializer)
+                }
+
+                #[doc(hidden)]
+                pub struct Ser #wrapper_impl_generics(pub &'__a #eident #ty_generics) #where_clause;
+
+                impl #wrapper_impl_generics ::serde::Serialize for Ser #wrapper_ty_generics #where_clause {
+                    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                    where
+                        S: ::serde::Serializer,
+                    {
+                        #eident::#ser_name(self.0, serializer)
+                    }
+                }
+
+                #[doc(hidden)]
+                pub struct De #impl_generics(pub #eident #ty_generics) #where_clause;
+
+                impl #de_impl_generics ::serde::Deserialize<'de> for De #ty_generics #where_clause {
+                    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                    where
+                        D: ::serde::Deserializer<'de>,
+                    {
+                        #eident::#de_name(deserializer).map(De)
+                    }
+                }
+
+                /// Same as the parent module for `Option<Enum>`
+                pub mod option {
+                    use super::*;
+
+                    pub fn serialize #ser_generics(
+                        value: &::std::option::Option<#eident #ty_generics>,
+                        serializer: S,
+                    ) -> ::std::result::Result<S::Ok, S::Error> #ser_where {
+                        ::serde::Serialize::serialize(&value.as_ref().map(Ser), serializer)
+                    }
+
+                    pub fn deserialize #de_generics(
+                        deserializer: D,
+                    ) -> ::std::result::Result<::std::option::Option<#eident #ty_generics>, D::Error> #de_where {
+                        let v = <::std::option::Option<De #ty_generics> as ::serde::Deserialize>::deserialize(deserializer)?;
+                        ::std::result::Result::Ok(v.map(|v| v.0))
+                    }
+                }
+
+                /// Same as the parent module for `Vec<Enum>`
+                pub mod vec {
+                    use super::*;
+
+                    pub fn serialize #ser_generics(
+                        value: &[#eident #ty_generics],
+                        serializer: S,
+                    ) -> ::std::result::Result<S::Ok, S::Error> #ser_where {
+                        serializer.collect_seq(value.iter().map(Ser))
+                    }
+
+                    pub fn deserialize #de_generics(
+                        deserializer: D,
+                    ) -> ::std::result::Result<::std::vec::Vec<#eident #ty_generics>, D::Error> #de_where {
+                        let v = <::std::vec::Vec<De #ty_generics> as ::serde::Deserialize>::deserialize(deserializer)?;
+                        ::std::result::Result::Ok(v.into_iter().map(|v| v.0).collect())
+                    }
+                }
+            }
+        }
+    }
+
+    /// Create impl block for `clap::ValueEnum`. Aliases are hidden aliases and doc comments of variants are help
+    /// texts of the possible values.
+    fn create_clap(&self, eident: &Ident, egenerics: &syn::Generics) -> proc_macro2::TokenStream {
+        if self.impl_clap.is_none() {
+            return quote! {};
+        }
+
+        // Variants without a possible value must not be returned from `value_variants`
+        let variants = self.clap_variants.iter().map(|(v, _)| v);
+        let arms = self.clap_variants.iter().map(|(variant, doc)| {
+            let r = self.rules.iter().find(|r| &r.variant == variant).expect("variant is mapped");
+            let pattern = r.pattern();
+            let value = r.to.literal();
+            let aliases = r.aliases.iter().map(|(a, _)| a.literal());
+            let help = doc.as_ref().map(|doc| quote! { .help(#doc) });
+            quote! {
+                #pattern => ::std::option::Option::Some(
+                    ::clap::builder::PossibleValue::new(#value)#(.alias(#aliases))* #help
+                ),
+            }
+        });
+        let def = (!self.is_exhaustive()).then(|| quote! { _ => ::std::option::Option::None, });
+
+        let (impl_generics, ty_generics, where_clause) = egenerics.split_for_impl();
+        quote! {
+            impl #impl_generics ::clap::ValueEnum for #eident #ty_generics #where_clause {
+                fn value_variants<'__a>() -> &'__a [Self] {
+                    &[#(Self::#variants),*]
+                }
+
+                fn to_possible_value(&self) -> ::std::option::Option<::clap::builder::PossibleValue> {
+                    match self {
+                        #(#arms)*
+                        #def
+                    }
+                }
+            }
+        }
+    }
+
+    /// Create impl blocks for `rusqlite::types::ToSql` and `rusqlite::types::FromSql`. Values are stored as text,
+    /// unknown strings are read as `default_from` or fail with `Parse<Enum>Error`.
+    fn create_sql(&self, eident: &Ident, egenerics: &syn::Generics) -> proc_macro2::TokenStream {
+        if !self.impl_sql {
+            return quote! {};
+        }
+
+        let name = &self.name;
+        let def = match &self.default_to {
+            _ if self.is_exhaustive() => quote! {},
+            Some(def) => quote! { _ => ::std::result::Result::Ok(::rusqlite::types::ToSqlOutput::from(#def)), },
+            None => {
+                let msg = format!("variant is not mapped in maping `{name}`");
+                quote! {
+                    _ => ::std::result::Result::Err(::rusqlite::Error::ToSqlConversionFailure(
+                        ::std::convert::From::from(#msg),
+                    )),
+                }
+            }
+        };
+        let arms = self.rules.iter().map(|r| {
+            let pattern = r.pattern();
+            let v = if r.to.is_literal() {
+                let s = r.to.literal();
+                quote! { #s }
+            } else {
+                r.format()
+            };
+            quote! { #pattern => ::std::result::Result::Ok(::rusqlite::types::ToSqlOutput::from(#v)), }
+        });
+
+        let error_ident = parse_error_ident(eident);
+        let expected = self.expected();
+        let fallback = match &self.default_from {
+            Some(def_from) => quote! { ::std::result::Result::Ok(Self::#def_from) },
+            None => quote! {
+                ::std::result::Result::Err(::rusqlite::types::FromSqlError::Other(::std::boxed::Box::new(
+                    #error_ident {
+                        input: ::std::string::String::from(s),
+                        mapping: #name,
+                        expected: &[#(#expected),*],
+                    }
+                )))
+            },
+        };
+        let body = self.parse_body(|v| quote! { ::std::result::Result::Ok(#v) }, fallback);
+
+        let (impl_generics, ty_generics, where_clause) = egenerics.split_for_impl();
+        quote! {
+            impl #impl_generics ::rusqlite::types::ToSql for #eident #ty_generics #where_clause {
+                fn to_sql(&self) -> ::rusqlite::Result<::rusqlite::types::ToSqlOutput<'_>> {
+                    match self {
+                        #(#arms)*
+                        #def
+                    }
+                }
+            }
+
+            impl #impl_generics ::rusqlite::types::FromSql for #eident #ty_generics #where_clause {
+                fn column_result(value: ::rusqlite::types::ValueRef<'_>) -> ::rusqlite::types::FromSqlResult<Self> {
+                    let s = value.as_str()?;
+                    #body
+                }
+            }
+        }
+    }
+}
+
+/// Single maping from variant to value of [`ValueMaping`]
+#[derive(Debug)]
+struct ValueRule {
+    variant: Ident,
+    fields: VariantFields,
+    value: syn::Expr,
+    span: proc_macro2::Span,
+    /// Values accepted by `from` functions instead of `value`
+    from_range: Option<FromRange>,
+}
+
+impl ValueRule {
+    /// Condition that matches `v` in `from` functions
+    fn parse_condition(&self) -> proc_macro2::TokenStream {
+        match &self.from_range {
+            Some(range) => range.condition(),
+            None => {
+                let value = &self.value;
+                quote! { v == #value }
+            }
+        }
+    }
+
+    /// Intervals of values matched in `from` functions. Values that are not integer literals are skipped.
+    fn parse_intervals(&self) -> Vec<(i128, i128, proc_macro2::Span)> {
+        match &self.from_range {
+            Some(range) => range.intervals(),
+            None => int_value(&self.value)
+                .map(|v| (v, v, self.span))
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    /// Pattern to match the variant of this rule without binding any fields
+    fn pattern(&self) -> proc_macro2::TokenStream {
+        let vident = &self.variant;
+        match &self.fields {
+            VariantFields::Unit => quote! { Self::#vident },
+            VariantFields::Unnamed(_) => quote! { Self::#vident(..) },
+            VariantFields::Named(_) => quote! { Self::#vident { .. } },
+        }
+    }
+}
+
+/// Maping from variants to values of type `ty` with `#[mapval(<name> = <expr>)]`
+#[derive(Debug)]
+struct ValueMaping {
+    name: String,
+    ty: Box<syn::Type>,
+    rules: Vec<ValueRule>,
+    /// Variants without a rule in this maping
+    unmapped: Vec<Ident>,
+    create_to: bool,
+    create_from: bool,
+    create_try: bool,
+    default_to: Option<syn::Expr>,
+    default_from: Option<proc_macro2::TokenStream>,
+    /// Default variant from maping declaration
+    default_variant: Option<Box<syn::Expr>>,
+    allow_duplicate: bool,
+    /// Span of `exhaustive` keyword, needed for error reporting
+    exhaustive: Option<proc_macro2::Span>,
+}
+
+impl ValueMaping {
+    /// Options that can be used in declaration of the maping
+    const OPTIONS: &'static [&'static str] = &[
+        "default_from",
+        "no_to",
+        "no_from",
+        "try",
+        "exhaustive",
+        "allow_duplicate",
+    ];
+
+    fn new(
+        name: String,
+        ty: Box<syn::Type>,
+        default_variant: Option<Box<syn::Expr>>,
+        options: MapingOptions,
+    ) -> Self {
+        Self {
+            name,
+            ty,
+            rules: Vec::new(),
+            unmapped: Vec::new(),
+            create_to: !options.no_to,
+            create_from: !options.no_from,
+            create_try: options.create_try,
+            default_to: None,
+            default_from: options.default_from,
+            default_variant,
+            allow_duplicate: options.allow_duplicate,
+            exhaustive: options.exhaustive.map(|kw| kw.span()),
+        }
+    }
+
+    /// Resolve defaults and check that the maping can be expanded. Must be called after all rules are added.
+    fn finish(&mut self, variants: &syn::punctuated::Punctuated<syn::Variant, Token![,]>) -> syn::Result<()> {
+        let mut errors = MultiError::new();
+
+        self.unmapped = variants
+            .iter()
+            .map(|v| &v.ident)
+            .filter(|v| !self.rules.iter().any(|r| &r.variant == *v))
+            .cloned()
+            .collect();
+
+        if let Some(expr) = self.default_variant.take() {
+            let vident = variant_ident(&expr).expect("checked when parsing");
+            match self.rules.iter().find(|r| &r.variant == vident) {
+                Some(rule) => {
+                    self.default_to = Some(rule.value.clone());
+                    if self.default_from.is_none() {
+                        self.default_from = Some(quote! { #expr });
+                    }
+                }
+                None => {
+                    let vident = vident.to_string();
+                    errors.update(Error::default_not_mapped(&vident, expr.span()).into());
+                }
+            }
+        }
+
+        if let (Some(span), false) = (self.exhaustive, self.unmapped.is_empty()) {
+            let unmapped = self
+                .unmapped
+                .iter()
+                .map(|v| format!("`{v}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            errors.update(Error::not_exhaustive(&unmapped, span).into());
+        }
+
+        if !self.allow_duplicate {
+            let values: Vec<_> = self.rules.iter().map(|r| value_key(&r.value)).collect();
+            for (i, r) in self.rules.iter().enumerate() {
+                if let Some(j) = (0..i).find(|&j| values[j] == values[i]) {
+                    let other = &self.rules[j];
+                    let other_variant = other.variant.to_string();
+                    let variant = r.variant.to_string();
+                    errors.update(Error::duplicate_value(&other_variant, r.span).into());
+                    errors.update(Error::duplicate_value(&variant, other.span).into());
+                }
+            }
+
+            if self.create_from {
+                let intervals: Vec<_> = self.rules.iter().map(|r| r.parse_intervals()).collect();
+                for (i, r) in self.rules.iter().enumerate() {
+                    if r.from_range.is_none() && self.rules[..i].iter().all(|o| o.from_range.is_none()) {
+                        // Exact values are already checked above
+                        continue;
+                    }
+                    let overlap = intervals[..i].iter().enumerate().find_map(|(j, other)| {
+                        intervals[i]
+                            .iter()
+                            .find(|(lo, hi, _)| other.iter().any(|(olo, ohi, _)| lo <= ohi && olo <= hi))
+                            .map(|(_, _, span)| (j, *span))
+                    });
+                    if let Some((j, span)) = overlap {
+                        let other = self.rules[j].variant.to_string();
+                        errors.update(Error::range_overlap(&other, span).into());
+                    }
+                }
+            }
+        }
+
+        errors.inner
+    }
+
+    fn expand(
+        &self,
+        eident: &syn::Ident,
+        evis: &syn::Visibility,
+        egenerics: &syn::Generics,
+    ) -> proc_macro2::TokenStream {
+        let to = self.create_to(evis);
+        let from = self.create_from(evis);
+
+        let (impl_generics, ty_generics, where_clause) = egenerics.split_for_impl();
+        quote! {
+            impl #impl_generics #eident #ty_generics #where_clause {
+                #to
+                #from
+            }
+        }
+    }
+
+    /// Create [try]_to function TokenStreams
+    fn create_to(&self, enum_vis: &syn::Visibility) -> proc_macro2::TokenStream {
+        if !self.create_to {
+            return quote! {};
+        }
+
+        let ty = &self.ty;
+        let exhaustive = self.unmapped.is_empty();
+        // Other expressions may call functions that are not const
+        let constness = self
+            .rules
+            .iter()
+            .map(|r| &r.value)
+            .chain(&self.default_to)
+            .all(is_literal)
+            .then(|| quote! { const });
+        let body = |found: &dyn Fn(&syn::Expr) -> proc_macro2::TokenStream, fallback| {
+            let patterns = self.rules.iter().map(|r| r.pattern());
+            let values = self.rules.iter().map(|r| found(&r.value));
+            let fallback = (!exhaustive).then(|| quote! { _ => #fallback });
+            quote! {
+                match self {
+                    #(#patterns => #values,)*
+                    #fallback
+                }
+            }
+        };
+
+        let to = |def_to: Option<&syn::Expr>| {
+            let to_fn_name = format_ident!("to_{}", self.name);
+            let body = body(&|v| quote! { #v }, quote! { #def_to });
+            quote! {
+                #enum_vis #constness fn #to_fn_name(&self) -> #ty {
+                    #body
+                }
+            }
+        };
+
+        let try_to = || {
+            let to_fn_name = format_ident!("try_to_{}", self.name);
+            let body = body(
+                &|v| quote! { ::std::option::Option::Some(#v) },
+                quote! { ::std::option::Option::None },
+            );
+            quote! {
+                #enum_vis #constness fn #to_fn_name(&self) -> ::std::option::Option<#ty> {
+                    #body
+                }
+            }
+        };
+
+        match (&self.default_to, self.create_try) {
+            // Every variant is mapped, `to` doesn't need a default
+            (None, _) if exhaustive => {
+                let to = to(None);
+                let try_to = try_to();
+                quote! {
+                    #to
+                    #try_to
+                }
+            }
+            (None, _) => try_to(),
+            (def_to, false) => to(def_to.as_ref()),
+            (def_to, true) => {
+                let to = to(def_to.as_ref());
+                let try_to = try_to();
+                quote! {
+                    #to
+                    #try_to
+                }
+            }
+        }
+    }
+
+    /// Create [try]_from function TokenStreams
+    fn create_from(&self, enum_vis: &syn::Visibility) -> proc_macro2::TokenStream {
+        if !self.create_from {
+            return quote! {};
+        }
+
+        let ty = &self.ty;
+        let body = |found: &dyn Fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream, fallback| {
+            let (values, ctors): (Vec<_>, Vec<_>) = self
+                .rules
+                .iter()
+                .filter_map(|r| {
+                    let ctor = r.fields.construct(&r.variant, |_| None)?;
+                    Some((r.parse_condition(), found(quote! { Self::#ctor })))
+                })
+                .unzip();
+            quote! {
+                match v {
+                    #(v if #values => #ctors,)*
+                    _ => #fallback
+                }
+            }
+        };
+
+        let from = |def_from| {
+            let from_fn_name = format_ident!("from_{}", self.name);
+            let body = body(&|v| v, quote! { Self::#def_from });
+            quote! {
+                #enum_vis fn #from_fn_name(v: #ty) -> Self {
+                    #body
+                }
+            }
+        };
+
+        let try_from = || {
+            let from_fn_name = format_ident!("try_from_{}", self.name);
+            let body = body(
+                &|v| quote! { ::std::option::Option::Some(#v) },
+                quote! { ::std::option::Option::None },
+            );
+            quote! {
+                #enum_vis fn #from_fn_name(v: #ty) -> ::std::option::Option<Self> {
+                    #body
+                }
+            }
+        };
+
+        match (&self.default_from, self.create_try) {
+            (Some(def_from), false) => from(def_from),
+            (Some(def_from), true) => {
+                let from = from(def_from);
+                let try_from = try_from();
+                quote! {
+                    #from
+                    #try_from
+                }
+            }
+            (None, _) => try_from(),
+        }
+    }
+}
+
+/// Field of metadata struct, type is declared with `#[mapmeta(<name>: <ty>)]` on the enum or inferred from values
+#[derive(Debug)]
+struct MetaField {
+    name: Ident,
+    ty: Option<Box<syn::Type>>,
+}
+
+/// Metadata records of variants from `#[mapmeta(<field> = <expr>, ..)]`, expanded into `<Enum>Meta` struct and
+/// static table returned by `meta()`
+#[derive(Debug, Default)]
+struct MetaTable {
+    fields: Vec<MetaField>,
+    /// Values of each variant in declaration order of variants
+    rows: Vec<MetaRow>,
+}
+
+/// Metadata values of single variant
+#[derive(Debug)]
+struct MetaRow {
+    variant: Ident,
+    fields: VariantFields,
+    values: Vec<NamedExpr>,
+    span: proc_macro2::Span,
+}
+
+impl MetaTable {
+    /// Parse `#[mapmeta(<name>: <ty>, ..)]` on the enum
+    fn parse_declaration(&mut self, attr: &syn::Attribute) -> syn::Result<()> {
+        let decls = attr.parse_args_with(syn::punctuated::Punctuated::<MetaFieldDeclaration, Token![,]>::parse_terminated)?;
+        for decl in decls {
+            if self.fields.iter().any(|f| f.name == decl.name) {
+                let name = decl.name.to_string();
+                return Err(Error::arg_set_twice(&name, decl.name.span()).into());
+            }
+            self.fields.push(MetaField {
+                name: decl.name,
+                ty: Some(decl.ty),
+            });
+        }
+        Ok(())
+    }
+
+    /// Parse `#[mapmeta(<field> = <expr>, ..)]` on a variant. Multiple attributes on the same variant are merged.
+    fn parse_variant_attribute(&mut self, vident: &Ident, attr: &syn::Attribute) -> syn::Result<()> {
+        let values = attr.parse_args_with(syn::punctuated::Punctuated::<NamedExpr, Token![,]>::parse_terminated)?;
+        let row = match self.rows.iter_mut().find(|r| &r.variant == vident) {
+            Some(row) => row,
+            None => {
+                self.rows.push(MetaRow {
+                    variant: vident.clone(),
+                    fields: VariantFields::Unit,
+                    values: Vec::new(),
+                    span: attr.path.span(),
+                });
+                self.rows.last_mut().unwrap()
+            }
+        };
+
+        for v in values {
+            if row.values.iter().any(|o| o.name == v.name) {
+                let name = v.name.to_string();
+                return Err(Error::arg_set_twice(&name, v.name.span()).into());
+            }
+            if !self.fields.iter().any(|f| f.name == v.name) {
+                self.fields.push(MetaField {
+                    name: v.name.clone(),
+                    ty: None,
+                });
+            }
+            row.values.push(v);
+        }
+        Ok(())
+    }
+
+    /// Order rows by variants, infer types and check that every variant sets every field
+    fn finish(&mut self, variants: &syn::punctuated::Punctuated<syn::Variant, Token![,]>) -> syn::Result<()> {
+        if self.rows.is_empty() {
+            return Ok(());
+        }
+
+        let mut errors = MultiError::new();
+        let span = self.rows[0].span;
+        let mut rows = std::mem::take(&mut self.rows);
+        let mut missing = Vec::new();
+        for v in variants {
+            match rows.iter().position(|r| r.variant == v.ident) {
+                Some(i) => {
+                    let mut row = rows.remove(i);
+                    row.fields = VariantFields::new(&v.fields);
+                    self.rows.push(row);
+                }
+                None => missing.push(format!("`{}`", v.ident)),
+            }
+        }
+        if !missing.is_empty() {
+            errors.update(Error::meta_not_set(&missing.join(", "), span).into());
+        }
+
+        for field in self.fields.iter_mut() {
+            for row in self.rows.iter() {
+                if !row.values.iter().any(|v| v.name == field.name) {
+                    let name = field.name.to_string();
+                    errors.update(Error::meta_field_not_set(&name, row.span).into());
+                }
+            }
+
+            if field.ty.is_none() {
+                field.ty = self
+                    .rows
+                    .iter()
+                    .flat_map(|r| r.values.iter())
+                    .filter(|v| v.name == field.name)
+                    .find_map(|v| infer_type(&v.value));
+            }
+            if field.ty.is_none() {
+                let name = field.name.to_string();
+                errors.update(Error::meta_type_not_inferred(&name, field.name.span()).into());
+            }
+        }
+
+        errors.inner
+    }
+
+    fn expand(
+        &self,
+        eident: &syn::Ident,
+        evis: &syn::Visibility,
+        egenerics: &syn::Generics,
+    ) -> proc_macro2::TokenStream {
+        if self.rows.is_empty() {
+            return quote! {};
+        }
+
+        let meta_ident = format_ident!("{}Meta", eident);
+        let doc = format!("Metadata of [`{eident}`] variants, returned by [`{eident}::meta`]");
+        let names = self.fields.iter().map(|f| &f.name).collect::<Vec<_>>();
+        let types = self.fields.iter().map(|f| &f.ty);
+
+        let len = self.rows.len();
+        let records = self.rows.iter().map(|r| {
+            let values = names.iter().map(|name| {
+                r.values.iter().find(|v| &v.name == *name).map(|v| &v.value)
+            });
+            quote! { #meta_ident { #(#names: #values),* } }
+        });
+        let patterns = self.rows.iter().map(|r| {
+            let vident = &r.variant;
+            match &r.fields {
+                VariantFields::Unit => quote! { Self::#vident },
+                VariantFields::Unnamed(_) => quote! { Self::#vident(..) },
+                VariantFields::Named(_) => quote! { Self::#vident { .. } },
+            }
+        });
+        let indices = 0..len;
+
+        let (impl_generics, ty_generics, where_clause) = egenerics.split_for_impl();
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, PartialEq)]
+            #evis struct #meta_ident {
+                #(#evis #names: #types,)*
+            }
+
+            impl #impl_generics #eident #ty_generics #where_clause {
+                /// Metadata record of the variant
+                #evis fn meta(&self) -> &'static #meta_ident {
+                    static META: [#meta_ident; #len] = [#(#records),*];
+                    match self {
+                        #(#patterns => &META[#indices],)*
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Declaration of metadata field `<name>: <ty>` in `#[mapmeta(..)]` on the enum
+#[allow(dead_code)]
+#[derive(Debug)]
+struct MetaFieldDeclaration {
+    name: Ident,
+    colon_token: Token![:],
+    ty: Box<syn::Type>,
+}
+
+impl syn::parse::Parse for MetaFieldDeclaration {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            name: input.parse()?,
+            colon_token: input.parse()?,
+            ty: input.parse()?,
+        })
+    }
+}
+
+/// Parameters from #[enum_map(..)]
+#[derive(Debug)]
+struct EnumMapArguments {
+    /// `tables` keyword, not followed by parenthesis as in maping declaration
+    tables: Option<kw::tables>,
+    mapings: Vec<MapingDeclaration>,
+}
+
+impl syn::parse::Parse for EnumMapArguments {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut tables = None;
+        let mut mapings = Vec::new();
+        while !input.is_empty() {
+            if input.peek(kw::tables) && !input.peek2(syn::token::Paren) {
+                let kw_token = input.parse::<kw::tables>()?;
+                if tables.is_some() {
+                    return Err(Error::arg_set_twice("tables", kw_token.span).into());
+                }
+                tables = Some(kw_token);
+            } else {
+                mapings.push(input.parse()?);
+            }
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        Ok(Self { tables, mapings })
+    }
+}
+
+/// Maping declared on the enum with `mapping(name = "..", ..)` or `<name>(..)`
+#[derive(Debug)]
+struct MapingDeclaration {
+    name: syn::LitStr,
+    /// Type of values for mapings to other types than strings
+    ty: Option<Box<syn::Type>>,
+    rename_all: Option<RenameRule>,
+    /// Variant set by `default = ..`, mapped value of it is used as `default_to`
+    default_variant: Option<Box<syn::Expr>>,
+    options: MapingOptions,
+}
+
+impl syn::parse::Parse for MapingDeclaration {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let (mut name, span) = if input.peek(kw::mapping) {
+            (None, input.parse::<kw::mapping>()?.span)
+        } else {
+            let ident = input.parse::<Ident>()?;
+            (Some(syn::LitStr::new(&ident.to_string(), ident.span())), ident.span())
+        };
+        let content;
+        syn::parenthesized!(content in input);
+        let args: syn::punctuated::Punctuated<_, Token![,]> =
+            content.parse_terminated(MapingDeclarationArgument::parse)?;
+
+        let mut ty = None;
+        let mut rename_all = None;
+        let mut default_variant = None;
+        let mut options = MapingOptions::default();
+        let mut option_args = Vec::new();
+        for arg in args {
+            match arg {
+                MapingDeclarationArgument::Name { value, .. } => {
+                    if name.is_none() {
+                        name = Some(value);
+                    } else {
+                        return Err(Error::arg_set_twice("name", value.span()).into());
+                    }
+                }
+                MapingDeclarationArgument::RenameAll { value, .. } => {
+                    if rename_all.is_some() {
+                        return Err(Error::arg_set_twice("rename_all", value.span()).into());
+                    }
+                    match RenameRule::from_str(&value.value()) {
+                        Some(r) => rename_all = Some(r),
+                        None => {
+                            let case = value.value();
+                            return Err(Error::unknown_case(&case, value.span()).into());
+                        }
+                    }
+                }
+                MapingDeclarationArgument::Ty { kw_token, value, .. } => {
+                    if ty.is_some() {
+                        return Err(Error::arg_set_twice("ty", kw_token.span).into());
+                    }
+                    ty = Some(value);
+                }
+                MapingDeclarationArgument::DefaultVariant { kw_token, value, .. } => {
+                    if default_variant.is_some() {
+                        return Err(Error::arg_set_twice("default", kw_token.span).into());
+                    }
+                    check_variant_expr(&value)?;
+                    default_variant = Some(value);
+                }
+                MapingDeclarationArgument::Option(arg) => {
+                    let span = arg.span();
+                    option_args.push((arg.keyword(), span));
+                    if let Some(arg) = options.set(arg, span)? {
+                        return Err(Error::variant_only(arg.keyword(), span).into());
+                    }
+                }
+            }
+        }
+
+        if ty.is_some() {
+            if let Some((kw, span)) = option_args.iter().find(|(kw, _)| !ValueMaping::OPTIONS.contains(kw)) {
+                return Err(Error::not_allowed_with_ty(kw, *span).into());
+            }
+            if rename_all.is_some() {
+                return Err(Error::not_allowed_with_ty("rename_all", span).into());
+            }
+        }
+
+        match name {
+            Some(name) => Ok(Self {
+                name,
+                ty,
+                rename_all,
+                default_variant,
+                options,
+            }),
+            None => Err(Error::arg_not_set("name", span).into()),
+        }
+    }
+}
+
+/// Single argument in maping declaration
+#[allow(dead_code)]
+#[derive(Debug)]
+enum MapingDeclarationArgument {
+    Name {
+        kw_token: kw::name,
+        eq_token: Token![=],
+        value: syn::LitStr,
+    },
+    RenameAll {
+        kw_token: kw::rename_all,
+        eq_token: Token![=],
+        value: syn::LitStr,
+    },
+    Ty {
+        kw_token: kw::ty,
+        eq_token: Token![=],
+        value: Box<syn::Type>,
+    },
+    DefaultVariant {
+        kw_token: kw::default,
+        eq_token: Token![=],
+        value: Box<syn::Expr>,
+    },
+    Option(MapStrArgument),
 }
 
-/// Single maping from variant to str
-#[derive(Debug)]
-struct MapingRule {
-    variant: Ident,
-    to: String,
-    has_fields: bool,
+impl syn::parse::Parse for MapingDeclarationArgument {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(kw::name) {
+            Ok(Self::Name {
+                kw_token: input.parse()?,
+                eq_token: input.parse()?,
+                value: input.parse()?,
+            })
+        } else if input.peek(kw::rename_all) {
+            Ok(Self::RenameAll {
+                kw_token: input.parse()?,
+                eq_token: input.parse()?,
+                value: input.parse()?,
+            })
+        } else if input.peek(kw::ty) {
+            Ok(Self::Ty {
+                kw_token: input.parse()?,
+                eq_token: input.parse()?,
+                value: input.parse()?,
+            })
+        } else if input.peek(kw::default) && input.peek2(Token![=]) {
+            Ok(Self::DefaultVariant {
+                kw_token: input.parse()?,
+                eq_token: input.parse()?,
+                value: input.parse()?,
+            })
+        } else {
+            input.parse().map(Self::Option)
+        }
+    }
 }
 
-/// One maping with `self.name`
-#[derive(Debug)]
-struct Maping {
-    name: String,
-    rules: Vec<MapingRule>,
-    create_to: bool,
-    create_from: bool,
+/// Options of the whole maping. Can be set on any of the variants or in maping declaration.
+#[derive(Debug, Default)]
+struct MapingOptions {
+    no_to: bool,
+    no_from: bool,
     default_to: Option<String>,
-    default_from: Option<Ident>,
+    default_from: Option<proc_macro2::TokenStream>,
     create_try: bool,
-    impl_display: bool,
+    normalize: Normalization,
+    allow_duplicate: bool,
+    impl_display: Option<kw::display>,
+    impl_from_str: Option<kw::from_str>,
+    impl_try_from_str: Option<kw::try_from_str>,
+    impl_into_str: Option<kw::into_str>,
+    impl_as_ref: Option<kw::as_ref>,
+    exhaustive: Option<kw::exhaustive>,
+    lit_macro: bool,
+    impl_serde: Option<kw::serde>,
+    serde_with: bool,
+    impl_clap: Option<kw::clap>,
+    impl_sql: Option<kw::sql>,
+    tables: Option<kw::tables>,
+    sql_ddl: Option<kw::sql_ddl>,
+    parse_prefix: Option<kw::parse_prefix>,
+    const_from: Option<kw::const_from>,
+    lookup: Option<Lookup>,
 }
 
-impl Maping {
-    fn expand(&mut self, eident: &syn::Ident, evis: &syn::Visibility) -> proc_macro2::TokenStream {
-        let (m_fields, m_no_fields): (Vec<_>, Vec<_>) = std::mem::take(&mut self.rules)
-            .into_iter()
-            .partition_map(|vm| {
-                let out = if vm.has_fields {
-                    Either::Left
+impl MapingOptions {
+    /// Set option from argument. Arguments specific to a variant are returned back.
+    fn set(&mut self, arg: MapStrArgument, span: proc_macro2::Span) -> syn::Result<Option<MapStrArgument>> {
+        match arg {
+            MapStrArgument::DefaultTo { value, .. } => {
+                if self.default_to.is_none() {
+                    self.default_to = Some(value.value());
                 } else {
-                    Either::Right
-                };
-                out((vm.variant, vm.to))
-            });
-        let v_fields = m_fields.into_iter().unzip();
-        let v_no_fields = m_no_fields.into_iter().unzip();
-
-        let to = self.create_to(evis, &v_fields, &v_no_fields);
-        let from = self.create_from(evis, &v_no_fields);
-
-        let display = self.create_display(eident, &v_fields, &v_no_fields);
-
-        quote! {
-            impl #eident {
-                #to
-                #from
+                    return Err(Error::arg_set_twice("default_to", span).into());
+                }
             }
-
-            #display
-        }
-    }
-
-    /// Create [try]_to function TokenStreams
-    fn create_to(
-        &self,
-        enum_vis: &syn::Visibility,
-        (v_idents_fields, v_str_fields): &(Vec<Ident>, Vec<String>),
-        (v_idents_no_fields, v_str_no_fields): &(Vec<Ident>, Vec<String>),
-    ) -> proc_macro2::TokenStream {
-        if !self.create_to {
-            return quote! {};
-        }
-
-        let to = |def_to| {
-            let to_fn_name = format_ident!("to_{}", self.name);
-            quote! {
-                #enum_vis fn #to_fn_name(&self) -> &'static str {
-                    match self {
-                        #(Self::#v_idents_no_fields => #v_str_no_fields,)*
-                        #(Self::#v_idents_fields(..) => #v_str_fields,)*
-                        _ => #def_to
-                    }
+            MapStrArgument::DefaultFrom { value, .. } => {
+                if self.default_from.is_none() {
+                    check_variant_expr(&value)?;
+                    self.default_from = Some(quote! { #value });
+                } else {
+                    return Err(Error::arg_set_twice("default_from", span).into());
                 }
             }
-        };
-
-        let try_to = || {
-            let to_fn_name = format_ident!("try_to_{}", self.name);
-            quote! {
-                #enum_vis fn #to_fn_name(&self) -> ::std::option::Option<&'static str> {
-                    match self {
-                        #(Self::#v_idents_no_fields => ::std::option::Option::Some(#v_str_no_fields),)*
-                        #(Self::#v_idents_fields(..) => ::std::option::Option::Some(#v_str_fields),)*
-                        _ => ::std::option::Option::None
-                    }
+            MapStrArgument::NoTo { .. } => {
+                self.no_to = true;
+            }
+            MapStrArgument::NoFrom { .. } => {
+                self.no_from = true;
+            }
+            MapStrArgument::Try { .. } => {
+                self.create_try = true;
+            }
+            MapStrArgument::Trim { .. } => {
+                self.normalize.trim = true;
+            }
+            MapStrArgument::IgnoreCase { .. } => {
+                self.normalize.ignore_case = true;
+            }
+            MapStrArgument::IgnoreAsciiCase { .. } => {
+                self.normalize.ignore_ascii_case = true;
+            }
+            MapStrArgument::IgnoreChars { value, .. } => {
+                if self.normalize.ignore_chars.is_none() {
+                    self.normalize.ignore_chars = Some(value.value());
+                } else {
+                    return Err(Error::arg_set_twice("ignore_chars", span).into());
                 }
             }
-        };
-
-        match (&self.default_to, &self.create_try) {
-            (Some(def_to), false) => to(def_to),
-            (Some(def_to), true) => {
-                let to = to(def_to);
-                let try_to = try_to();
-                quote! {
-                    #to
-                    #try_to
+            MapStrArgument::AllowDuplicate { .. } => {
+                self.allow_duplicate = true;
+            }
+            MapStrArgument::Exhaustive { kw_token } => {
+                self.exhaustive = Some(kw_token);
+            }
+            MapStrArgument::ImplDisplay { kw_token } => {
+                self.impl_display = Some(kw_token);
+            }
+            MapStrArgument::ImplFromStr { kw_token } => {
+                self.impl_from_str = Some(kw_token);
+            }
+            MapStrArgument::ImplTryFromStr { kw_token } => {
+                self.impl_try_from_str = Some(kw_token);
+            }
+            MapStrArgument::ImplIntoStr { kw_token } => {
+                self.impl_into_str = Some(kw_token);
+            }
+            MapStrArgument::ImplAsRef { kw_token } => {
+                self.impl_as_ref = Some(kw_token);
+            }
+            MapStrArgument::LitMacro { .. } => {
+                self.lit_macro = true;
+            }
+            MapStrArgument::ImplSerde { kw_token } => {
+                if !cfg!(feature = "serde") {
+                    return Err(Error::feature_not_enabled("serde", span).into());
                 }
+                self.impl_serde = Some(kw_token);
             }
-            (None, _) => try_to(),
-        }
-    }
-
-    /// Create [try_]from functions TokenStreams.
-    fn create_from(
-        &self,
-        enum_vis: &syn::Visibility,
-        (v_idents_no_fields, v_str_no_fields): &(Vec<Ident>, Vec<String>),
-    ) -> proc_macro2::TokenStream {
-        if !self.create_from {
-            return quote! {};
-        }
-
-        let from = |def_from| {
-            let from_fn_name = format_ident!("from_{}", self.name);
-            quote! {
-                #enum_vis fn #from_fn_name(s: &str) -> Self {
-                    match s {
-                        #(s if s == #v_str_no_fields => Self::#v_idents_no_fields,)*
-                        _ => Self::#def_from
-                    }
+            MapStrArgument::SerdeWith { .. } => {
+                if !cfg!(feature = "serde") {
+                    return Err(Error::feature_not_enabled("serde", span).into());
                 }
+                self.serde_with = true;
             }
-        };
-
-        let try_from = || {
-            let from_fn_name = format_ident!("try_from_{}", self.name);
-            quote! {
-                #enum_vis fn #from_fn_name(s: &str) -> ::std::option::Option<Self> {
-                    match s {
-                        #(s if s == #v_str_no_fields => ::std::option::Option::Some(Self::#v_idents_no_fields),)*
-                        _ => None
-                    }
+            MapStrArgument::ImplClap { kw_token } => {
+                if !cfg!(feature = "clap") {
+                    return Err(Error::feature_not_enabled("clap", span).into());
                 }
+                self.impl_clap = Some(kw_token);
             }
-        };
-
-        match (&self.default_from, &self.create_try) {
-            (Some(def_from), false) => from(def_from),
-            (Some(def_from), true) => {
-                let from = from(def_from);
-                let try_from = try_from();
-                quote! {
-                    #from
-                    #try_from
+            MapStrArgument::ImplSql { kw_token } => {
+                if !cfg!(feature = "rusqlite") {
+                    return Err(Error::feature_not_enabled("rusqlite", span).into());
                 }
+                self.impl_sql = Some(kw_token);
             }
-            (None, _) => try_from(),
-        }
-    }
-
-    /// Create impl block for Display trait
-    fn create_display(
-        &self,
-        eident: &Ident,
-        (v_idents_fields, v_str_fields): &(Vec<Ident>, Vec<String>),
-        (v_idents_no_fields, v_str_no_fields): &(Vec<Ident>, Vec<String>),
-    ) -> proc_macro2::TokenStream {
-        if !self.impl_display {
-            return quote! {};
-        }
-
-        let def = if let Some(ref def) = self.default_to {
-            def.clone()
-        } else {
-            String::from("Unknown variant")
-        };
-
-        quote! {
-            impl ::std::fmt::Display for #eident {
-                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    match self {
-                        #(Self::#v_idents_no_fields => write!(f, #v_str_no_fields),)*
-                        #(Self::#v_idents_fields(..) => write!(#v_str_fields),)*
-                        _ => write!(f,  #def)
+            MapStrArgument::Tables { kw_token } => {
+                self.tables = Some(kw_token);
+            }
+            MapStrArgument::SqlDdl { kw_token } => {
+                self.sql_ddl = Some(kw_token);
+            }
+            MapStrArgument::ParsePrefix { kw_token } => {
+                self.parse_prefix = Some(kw_token);
+            }
+            MapStrArgument::ConstFrom { kw_token } => {
+                self.const_from = Some(kw_token);
+            }
+            MapStrArgument::Lookup { value, .. } => {
+                if self.lookup.is_some() {
+                    return Err(Error::arg_set_twice("lookup", span).into());
+                }
+                match Lookup::from_str(&value.value()) {
+                    Some(l) => self.lookup = Some(l),
+                    None => {
+                        let lookup = value.value();
+                        return Err(Error::unknown_lookup(&lookup, value.span()).into());
                     }
                 }
             }
+            arg => return Ok(Some(arg)),
         }
+        Ok(None)
     }
 }
+
 /// Parameters from one #[mapstr(..)]
 #[derive(Debug)]
 struct MapStrArguments {
     name: Option<syn::LitStr>,
-    mapped_value: String,
-    create_to: bool,
-    create_from: bool,
-    default_to: Option<String>,
-    default_from: Option<Ident>,
+    mapped_value: syn::LitStr,
     is_default: bool,
-    create_try: bool,
-    impl_display: Option<kw::display>,
+    primary: bool,
+    fields: Option<FieldValues>,
+    aliases: Vec<syn::LitStr>,
+    options: MapingOptions,
 }
 
 impl MapStrArguments {
-    fn finalize(mut self, vident: &syn::Ident) -> Self {
+    /// Arguments with only name and value set, used by `mapstr(<name> = "..", ..)`
+    fn new(name: syn::LitStr, mapped_value: syn::LitStr) -> Self {
+        Self {
+            name: Some(name),
+            mapped_value,
+            is_default: false,
+            primary: false,
+            fields: None,
+            aliases: Vec::new(),
+            options: MapingOptions::default(),
+        }
+    }
+
+    fn finalize(mut self, vident: &syn::Ident, fields: &VariantFields) -> Self {
         if !self.is_default {
             return self;
         }
 
-        if self.default_to.is_none() {
-            self.default_to = Some(self.mapped_value.clone());
+        if self.options.default_to.is_none() {
+            self.options.default_to = Some(self.mapped_value.value());
         }
 
-        if self.default_from.is_none() {
-            self.default_from = Some(vident.clone());
+        if self.options.default_from.is_none() {
+            // If fields cannot be constructed, let the compiler complain about missing fields
+            let values = self.fields.as_ref();
+            self.options.default_from = fields
+                .construct(vident, |f| values.and_then(|v| v.get(f)))
+                .or_else(|| Some(quote! { #vident }));
         }
 
         self
@@ -398,21 +3284,18 @@ impl syn::parse::Parse for MapStrArguments {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         // Value must be first positional argument. It doesn't necessarily have to be but let's make syntax clear by forcing it.
         let lookahead = input.lookahead1();
-        let mapped_value: String;
-        if lookahead.peek(syn::LitStr) {
-            mapped_value = input.parse::<syn::LitStr>()?.value();
+        let mapped_value = if lookahead.peek(syn::LitStr) {
+            input.parse::<syn::LitStr>()?
         } else {
             return Err(Error::arg_not_set("value", input.span()).into());
-        }
+        };
 
         let mut name = None;
-        let mut create_to = true;
-        let mut create_from = true;
-        let mut default_to = None;
-        let mut default_from = None;
         let mut is_default = false;
-        let mut create_try = false;
-        let mut impl_display = None;
+        let mut primary = false;
+        let mut fields = None;
+        let mut aliases = Vec::new();
+        let mut options = MapingOptions::default();
 
         // There is somewhat optional comma. It's optional if `name` has been specified before.
         // It's not if we expect something afterwards. Parse anything after only is comma was found.
@@ -422,6 +3305,10 @@ impl syn::parse::Parse for MapStrArguments {
                 input.parse_terminated(MapStrArgument::parse)?;
 
             for arg in args {
+                let arg = match options.set(arg, input.span())? {
+                    Some(arg) => arg,
+                    None => continue,
+                };
                 match arg {
                     MapStrArgument::Name { value, .. } => {
                         if name.is_none() {
@@ -430,35 +3317,27 @@ impl syn::parse::Parse for MapStrArguments {
                             return Err(Error::arg_set_twice("name", input.span()).into());
                         }
                     }
-                    MapStrArgument::DefaultTo { value, .. } => {
-                        if default_to.is_none() {
-                            default_to = Some(value.value());
-                        } else {
-                            return Err(Error::arg_set_twice("default_to", input.span()).into());
-                        }
-                    }
-                    MapStrArgument::DefaultFrom { value, .. } => {
-                        if default_from.is_none() {
-                            default_from = Some(value);
-                        } else {
-                            return Err(Error::arg_set_twice("default_from", input.span()).into());
-                        }
-                    }
                     MapStrArgument::Default { .. } => {
                         is_default = true;
                     }
-                    MapStrArgument::NoTo { .. } => {
-                        create_to = false;
+                    MapStrArgument::Primary { .. } => {
+                        primary = true;
                     }
-                    MapStrArgument::NoFrom { .. } => {
-                        create_from = false;
-                    }
-                    MapStrArgument::Try { .. } => {
-                        create_try = true;
+                    MapStrArgument::Fields { value, .. } => {
+                        if fields.is_none() {
+                            fields = Some(value);
+                        } else {
+                            return Err(Error::arg_set_twice("fields", input.span()).into());
+                        }
                     }
-                    MapStrArgument::ImplDisplay { kw_token } => {
-                        impl_display = Some(kw_token);
+                    MapStrArgument::Aliases { value, .. } => {
+                        if aliases.is_empty() {
+                            aliases = value.into_iter().collect();
+                        } else {
+                            return Err(Error::arg_set_twice("aliases", input.span()).into());
+                        }
                     }
+                    _ => unreachable!("maping options are handled by `MapingOptions::set`"),
                 };
             }
         }
@@ -466,17 +3345,205 @@ impl syn::parse::Parse for MapStrArguments {
         Ok(Self {
             name,
             mapped_value,
-            default_to,
-            default_from,
-            create_to,
-            create_from,
-            create_try,
             is_default,
-            impl_display,
+            primary,
+            fields,
+            aliases,
+            options,
+        })
+    }
+}
+
+/// Value for one of the declared mapings in `mapstr(<name> = "..", ..)`
+#[allow(dead_code)]
+#[derive(Debug)]
+struct NamedValue {
+    name: Ident,
+    eq_token: Token![=],
+    value: syn::LitStr,
+}
+
+impl syn::parse::Parse for NamedValue {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            name: input.parse()?,
+            eq_token: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
+
+/// Value for one of the mapings in `mapval(<name> = <expr>, ..)`
+#[allow(dead_code)]
+#[derive(Debug)]
+struct NamedExpr {
+    name: Ident,
+    eq_token: Token![=],
+    value: syn::Expr,
+}
+
+impl syn::parse::Parse for NamedExpr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            name: input.parse()?,
+            eq_token: input.parse()?,
+            value: input.parse()?,
         })
     }
 }
 
+/// Values given by `from_range = ..`, either single range like `500..=599` or list of ranges and values like
+/// `[401, 403, 500..600]`
+#[derive(Debug, Clone)]
+struct FromRange {
+    items: Vec<syn::Expr>,
+}
+
+impl FromRange {
+    fn new(expr: &syn::Expr) -> Self {
+        let items = match expr {
+            syn::Expr::Array(a) => a.elems.iter().cloned().collect(),
+            expr => vec![expr.clone()],
+        };
+        Self { items }
+    }
+
+    /// Condition that matches `v` against any of the items
+    fn condition(&self) -> proc_macro2::TokenStream {
+        let conditions = self.items.iter().map(|item| match item {
+            syn::Expr::Range(range) => quote! { (#range).contains(&v) },
+            value => quote! { v == #value },
+        });
+        quote! { #(#conditions)||* }
+    }
+
+    /// Inclusive intervals of the items. Items with bounds that are not integer literals are skipped.
+    fn intervals(&self) -> Vec<(i128, i128, proc_macro2::Span)> {
+        self.items
+            .iter()
+            .filter_map(|item| {
+                let (lo, hi) = match item {
+                    syn::Expr::Range(range) => {
+                        let lo = match &range.from {
+                            Some(from) => int_value(from)?,
+                            None => i128::MIN,
+                        };
+                        let hi = match (&range.to, &range.limits) {
+                            (Some(to), syn::RangeLimits::Closed(_)) => int_value(to)?,
+                            (Some(to), syn::RangeLimits::HalfOpen(_)) => int_value(to)? - 1,
+                            (None, _) => i128::MAX,
+                        };
+                        (lo, hi)
+                    }
+                    value => {
+                        let v = int_value(value)?;
+                        (v, v)
+                    }
+                };
+                Some((lo, hi, item.span()))
+            })
+            .collect()
+    }
+}
+
+/// Value of integer literal like `404`, `-1` or `500u16`
+fn int_value(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(i), .. }) => i.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => int_value(expr).map(|v: i128| -v),
+        syn::Expr::Group(g) => int_value(&g.expr),
+        syn::Expr::Paren(p) => int_value(&p.expr),
+        _ => None,
+    }
+}
+
+/// Key to find duplicate values. Integers are compared by value, so `404u16`, `404` and `0x194` are equal,
+/// suffixes of other literals are ignored.
+fn value_key(expr: &syn::Expr) -> String {
+    if let Some(v) = int_value(expr) {
+        return v.to_string();
+    }
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => match lit {
+            syn::Lit::Str(s) => format!("{:?}", s.value()),
+            syn::Lit::ByteStr(s) => format!("b{:?}", s.value()),
+            syn::Lit::Byte(b) => b.value().to_string(),
+            syn::Lit::Char(c) => format!("{:?}", c.value()),
+            syn::Lit::Int(i) => i.base10_digits().to_string(),
+            syn::Lit::Float(f) => f.base10_digits().to_string(),
+            lit => lit.to_token_stream().to_string(),
+        },
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => format!("-{}", value_key(expr)),
+        syn::Expr::Group(g) => value_key(&g.expr),
+        syn::Expr::Paren(p) => value_key(&p.expr),
+        _ => expr.to_token_stream().to_string(),
+    }
+}
+
+/// Value is a literal like `404`, `-1` or `b"tag"`
+fn is_literal(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Lit(_) => true,
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => is_literal(expr),
+        syn::Expr::Group(g) => is_literal(&g.expr),
+        syn::Expr::Paren(p) => is_literal(&p.expr),
+        _ => false,
+    }
+}
+
+/// Type of literal value, `None` if literal is unsuffixed number or value is not a literal
+fn infer_type(expr: &syn::Expr) -> Option<Box<syn::Type>> {
+    let lit = match expr {
+        syn::Expr::Lit(l) => &l.lit,
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => match &**expr {
+            syn::Expr::Lit(l) => &l.lit,
+            _ => return None,
+        },
+        syn::Expr::Group(g) => return infer_type(&g.expr),
+        _ => return None,
+    };
+
+    let ty = match lit {
+        syn::Lit::Str(_) => quote! { &'static str },
+        syn::Lit::ByteStr(_) => quote! { &'static [u8] },
+        syn::Lit::Byte(_) => quote! { u8 },
+        syn::Lit::Char(_) => quote! { char },
+        syn::Lit::Bool(_) => quote! { bool },
+        syn::Lit::Int(i) if !i.suffix().is_empty() => {
+            let suffix = format_ident!("{}", i.suffix());
+            quote! { #suffix }
+        }
+        syn::Lit::Float(f) if !f.suffix().is_empty() => {
+            let suffix = format_ident!("{}", f.suffix());
+            quote! { #suffix }
+        }
+        _ => return None,
+    };
+    syn::parse2(ty).ok()
+}
+
+/// Variant name from `Variant`, `Variant(..)` or `Variant { .. }`
+fn variant_ident(expr: &syn::Expr) -> Option<&Ident> {
+    match expr {
+        syn::Expr::Path(p) => p.path.get_ident(),
+        syn::Expr::Call(c) => match &*c.func {
+            syn::Expr::Path(p) => p.path.get_ident(),
+            _ => None,
+        },
+        syn::Expr::Struct(s) => s.path.get_ident(),
+        _ => None,
+    }
+}
+
+/// Check that `default_from` value is a variant: `Variant`, `Variant(..)` or `Variant { .. }`
+fn check_variant_expr(expr: &syn::Expr) -> syn::Result<()> {
+    if variant_ident(expr).is_some() {
+        Ok(())
+    } else {
+        Err(Error::expected_variant(expr.span()).into())
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 enum MapStrArgument {
@@ -493,7 +3560,7 @@ enum MapStrArgument {
     DefaultFrom {
         kw_token: kw::default_from,
         eq_token: Token![=],
-        value: syn::Ident,
+        value: Box<syn::Expr>,
     },
     NoTo {
         kw_token: kw::no_to,
@@ -507,9 +3574,163 @@ enum MapStrArgument {
     Try {
         kw_token: kw::r#try,
     },
+    Trim {
+        kw_token: kw::trim,
+    },
+    IgnoreCase {
+        kw_token: kw::ignore_case,
+    },
+    IgnoreAsciiCase {
+        kw_token: kw::ignore_ascii_case,
+    },
+    IgnoreChars {
+        kw_token: kw::ignore_chars,
+        eq_token: Token![=],
+        value: syn::LitStr,
+    },
+    AllowDuplicate {
+        kw_token: kw::allow_duplicate,
+    },
+    Primary {
+        kw_token: kw::primary,
+    },
+    Exhaustive {
+        kw_token: kw::exhaustive,
+    },
     ImplDisplay {
         kw_token: kw::display,
     },
+    ImplFromStr {
+        kw_token: kw::from_str,
+    },
+    ImplTryFromStr {
+        kw_token: kw::try_from_str,
+    },
+    ImplIntoStr {
+        kw_token: kw::into_str,
+    },
+    ImplAsRef {
+        kw_token: kw::as_ref,
+    },
+    LitMacro {
+        kw_token: kw::lit_macro,
+    },
+    ImplSerde {
+        kw_token: kw::serde,
+    },
+    SerdeWith {
+        kw_token: kw::serde_with,
+    },
+    ImplClap {
+        kw_token: kw::clap,
+    },
+    ImplSql {
+        kw_token: kw::sql,
+    },
+    Tables {
+        kw_token: kw::tables,
+    },
+    SqlDdl {
+        kw_token: kw::sql_ddl,
+    },
+    ParsePrefix {
+        kw_token: kw::parse_prefix,
+    },
+    ConstFrom {
+        kw_token: kw::const_from,
+    },
+    Lookup {
+        kw_token: kw::lookup,
+        eq_token: Token![=],
+        value: syn::LitStr,
+    },
+    Fields {
+        kw_token: kw::fields,
+        value: FieldValues,
+    },
+    Aliases {
+        kw_token: kw::aliases,
+        value: syn::punctuated::Punctuated<syn::LitStr, Token![,]>,
+    },
+}
+
+impl MapStrArgument {
+    /// Keywords of arguments that are set with `<keyword> = ..`
+    const EQ_KEYWORDS: &'static [&'static str] = &["name", "default_to", "default_from", "ignore_chars", "lookup"];
+
+    /// Keyword of the argument as written in attribute
+    fn keyword(&self) -> &'static str {
+        match self {
+            Self::Name { .. } => "name",
+            Self::DefaultTo { .. } => "default_to",
+            Self::DefaultFrom { .. } => "default_from",
+            Self::NoTo { .. } => "no_to",
+            Self::NoFrom { .. } => "no_from",
+            Self::Default { .. } => "default",
+            Self::Try { .. } => "try",
+            Self::Trim { .. } => "trim",
+            Self::IgnoreCase { .. } => "ignore_case",
+            Self::IgnoreAsciiCase { .. } => "ignore_ascii_case",
+            Self::IgnoreChars { .. } => "ignore_chars",
+            Self::AllowDuplicate { .. } => "allow_duplicate",
+            Self::Primary { .. } => "primary",
+            Self::Exhaustive { .. } => "exhaustive",
+            Self::ImplDisplay { .. } => "display",
+            Self::ImplFromStr { .. } => "from_str",
+            Self::ImplTryFromStr { .. } => "try_from_str",
+            Self::ImplIntoStr { .. } => "into_str",
+            Self::ImplAsRef { .. } => "as_ref",
+            Self::LitMacro { .. } => "lit_macro",
+            Self::ImplSerde { .. } => "serde",
+            Self::SerdeWith { .. } => "serde_with",
+            Self::ImplClap { .. } => "clap",
+            Self::ImplSql { .. } => "sql",
+            Self::Tables { .. } => "tables",
+            Self::SqlDdl { .. } => "sql_ddl",
+            Self::ParsePrefix { .. } => "parse_prefix",
+            Self::ConstFrom { .. } => "const_from",
+            Self::Lookup { .. } => "lookup",
+            Self::Fields { .. } => "fields",
+            Self::Aliases { .. } => "aliases",
+        }
+    }
+
+    /// Span of the keyword
+    fn span(&self) -> proc_macro2::Span {
+        match self {
+            Self::Name { kw_token, .. } => kw_token.span,
+            Self::DefaultTo { kw_token, .. } => kw_token.span,
+            Self::DefaultFrom { kw_token, .. } => kw_token.span,
+            Self::NoTo { kw_token } => kw_token.span,
+            Self::NoFrom { kw_token } => kw_token.span,
+            Self::Default { kw_token } => kw_token.span,
+            Self::Try { kw_token } => kw_token.span,
+            Self::Trim { kw_token } => kw_token.span,
+            Self::IgnoreCase { kw_token } => kw_token.span,
+            Self::IgnoreAsciiCase { kw_token } => kw_token.span,
+            Self::IgnoreChars { kw_token, .. } => kw_token.span,
+            Self::AllowDuplicate { kw_token } => kw_token.span,
+            Self::Primary { kw_token } => kw_token.span,
+            Self::Exhaustive { kw_token } => kw_token.span,
+            Self::ImplDisplay { kw_token } => kw_token.span,
+            Self::ImplFromStr { kw_token } => kw_token.span,
+            Self::ImplTryFromStr { kw_token } => kw_token.span,
+            Self::ImplIntoStr { kw_token } => kw_token.span,
+            Self::ImplAsRef { kw_token } => kw_token.span,
+            Self::LitMacro { kw_token } => kw_token.span,
+            Self::ImplSerde { kw_token } => kw_token.span,
+            Self::SerdeWith { kw_token } => kw_token.span,
+            Self::ImplClap { kw_token } => kw_token.span,
+            Self::ImplSql { kw_token } => kw_token.span,
+            Self::Tables { kw_token } => kw_token.span,
+            Self::SqlDdl { kw_token } => kw_token.span,
+            Self::ParsePrefix { kw_token } => kw_token.span,
+            Self::ConstFrom { kw_token } => kw_token.span,
+            Self::Lookup { kw_token, .. } => kw_token.span,
+            Self::Fields { kw_token, .. } => kw_token.span,
+            Self::Aliases { kw_token, .. } => kw_token.span,
+        }
+    }
 }
 
 impl syn::parse::Parse for MapStrArgument {
@@ -548,8 +3769,63 @@ impl syn::parse::Parse for MapStrArgument {
             item_kw!(Default)
         } else if lookahead.peek(kw::r#try) {
             item_kw!(Try)
+        } else if lookahead.peek(kw::trim) {
+            item_kw!(Trim)
+        } else if lookahead.peek(kw::ignore_case) {
+            item_kw!(IgnoreCase)
+        } else if lookahead.peek(kw::ignore_ascii_case) {
+            item_kw!(IgnoreAsciiCase)
+        } else if lookahead.peek(kw::ignore_chars) {
+            item_eq!(IgnoreChars)
+        } else if lookahead.peek(kw::allow_duplicate) {
+            item_kw!(AllowDuplicate)
+        } else if lookahead.peek(kw::primary) {
+            item_kw!(Primary)
+        } else if lookahead.peek(kw::exhaustive) {
+            item_kw!(Exhaustive)
         } else if lookahead.peek(kw::display) {
             item_kw!(ImplDisplay)
+        } else if lookahead.peek(kw::from_str) {
+            item_kw!(ImplFromStr)
+        } else if lookahead.peek(kw::try_from_str) {
+            item_kw!(ImplTryFromStr)
+        } else if lookahead.peek(kw::into_str) {
+            item_kw!(ImplIntoStr)
+        } else if lookahead.peek(kw::as_ref) {
+            item_kw!(ImplAsRef)
+        } else if lookahead.peek(kw::lit_macro) {
+            item_kw!(LitMacro)
+        } else if lookahead.peek(kw::serde) {
+            item_kw!(ImplSerde)
+        } else if lookahead.peek(kw::serde_with) {
+            item_kw!(SerdeWith)
+        } else if lookahead.peek(kw::clap) {
+            item_kw!(ImplClap)
+        } else if lookahead.peek(kw::sql) {
+            item_kw!(ImplSql)
+        } else if lookahead.peek(kw::tables) {
+            item_kw!(Tables)
+        } else if lookahead.peek(kw::sql_ddl) {
+            item_kw!(SqlDdl)
+        } else if lookahead.peek(kw::parse_prefix) {
+            item_kw!(ParsePrefix)
+        } else if lookahead.peek(kw::const_from) {
+            item_kw!(ConstFrom)
+        } else if lookahead.peek(kw::lookup) {
+            item_eq!(Lookup)
+        } else if lookahead.peek(kw::fields) {
+            Ok(Self::Fields {
+                kw_token: input.parse()?,
+                value: input.parse()?,
+            })
+        } else if lookahead.peek(kw::aliases) {
+            let kw_token = input.parse()?;
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(Self::Aliases {
+                kw_token,
+                value: content.parse_terminated(<syn::LitStr as Parse>::parse)?,
+            })
         } else {
             Err(lookahead.error())
         }
@@ -1,3 +1,6 @@
+use crate::case::RenameRule;
+use crate::lookup::Lookup;
+
 /// Wrapper to simplify combining multiple errors into one
 pub(crate) struct MultiError {
     pub(crate) inner: syn::Result<()>,
@@ -32,7 +35,6 @@ impl MultiError {
 //         span: proc_macro2::Span,
 //     },
 // }
-
 pub(crate) struct Error<'a> {
     error: ErrorType<'a>,
     span: proc_macro2::Span
@@ -66,6 +68,216 @@ impl<'a> Error<'a> {
             span
         }
     }
+
+    pub(crate) fn invalid_template(msg: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::InvalidTemplate(msg),
+            span
+        }
+    }
+
+    pub(crate) fn ambiguous_template(other: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::AmbiguousTemplate(other),
+            span
+        }
+    }
+
+    pub(crate) fn adjacent_fields(span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::AdjacentFields,
+            span
+        }
+    }
+
+    pub(crate) fn field_set_twice(field: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::FieldSetTwice(field),
+            span
+        }
+    }
+
+    pub(crate) fn field_not_set(field: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::FieldNotSet(field),
+            span
+        }
+    }
+
+    pub(crate) fn expected_variant(span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::ExpectedVariant,
+            span
+        }
+    }
+
+    pub(crate) fn template_not_allowed(kw: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::TemplateNotAllowed(kw),
+            span
+        }
+    }
+
+    pub(crate) fn same_after_normalization(other: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::SameAfterNormalization(other),
+            span
+        }
+    }
+
+    pub(crate) fn duplicate_value(other: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::DuplicateValue(other),
+            span
+        }
+    }
+
+    pub(crate) fn primary_not_set(other: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::PrimaryNotSet(other),
+            span
+        }
+    }
+
+    pub(crate) fn primary_set_twice(other: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::PrimarySetTwice(other),
+            span
+        }
+    }
+
+    pub(crate) fn not_exhaustive(unmapped: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::NotExhaustive(unmapped),
+            span
+        }
+    }
+
+    pub(crate) fn unknown_case(case: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::UnknownCase(case),
+            span
+        }
+    }
+
+    pub(crate) fn variant_only(kw: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::VariantOnly(kw),
+            span
+        }
+    }
+
+    pub(crate) fn default_not_mapped(variant: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::DefaultNotMapped(variant),
+            span
+        }
+    }
+
+    pub(crate) fn wrong_attribute(attr: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::WrongAttribute(attr),
+            span
+        }
+    }
+
+    pub(crate) fn type_not_inferred(name: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::TypeNotInferred(name),
+            span
+        }
+    }
+
+    pub(crate) fn not_allowed_with_ty(kw: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::NotAllowedWithTy(kw),
+            span
+        }
+    }
+
+    pub(crate) fn from_range_single_maping(span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::FromRangeSingleMaping,
+            span
+        }
+    }
+
+    pub(crate) fn range_overlap(other: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::RangeOverlap(other),
+            span
+        }
+    }
+
+    pub(crate) fn meta_not_set(variants: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::MetaNotSet(variants),
+            span
+        }
+    }
+
+    pub(crate) fn meta_field_not_set(field: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::MetaFieldNotSet(field),
+            span
+        }
+    }
+
+    pub(crate) fn meta_type_not_inferred(field: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::MetaTypeNotInferred(field),
+            span
+        }
+    }
+
+    pub(crate) fn unknown_lookup(lookup: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::UnknownLookup(lookup),
+            span
+        }
+    }
+
+    pub(crate) fn feature_not_enabled(feature: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::FeatureNotEnabled(feature),
+            span
+        }
+    }
+
+    pub(crate) fn unit_variants_only(kw: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::UnitVariantsOnly(kw),
+            span
+        }
+    }
+
+    pub(crate) fn normalization_not_supported(kw: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::NormalizationNotSupported(kw),
+            span
+        }
+    }
+
+    pub(crate) fn unknown_maping(name: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::UnknownMaping(name),
+            span
+        }
+    }
+
+    pub(crate) fn generated_name_collision(name: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::GeneratedNameCollision(name),
+            span
+        }
+    }
+
+    pub(crate) fn unknown_field(field: &'a str, span: proc_macro2::Span) -> Self {
+        Self {
+            error: ErrorType::UnknownField(field),
+            span
+        }
+    }
 }
 
 pub(crate) enum ErrorType<'a> {
@@ -73,6 +285,36 @@ pub(crate) enum ErrorType<'a> {
     ArgNotSet(&'a str),
     TraitAlreadyImplemented(&'a str),
     DuplicateMaping(&'a str),
+    InvalidTemplate(&'a str),
+    UnknownField(&'a str),
+    AmbiguousTemplate(&'a str),
+    AdjacentFields,
+    FieldSetTwice(&'a str),
+    FieldNotSet(&'a str),
+    ExpectedVariant,
+    TemplateNotAllowed(&'a str),
+    SameAfterNormalization(&'a str),
+    DuplicateValue(&'a str),
+    PrimaryNotSet(&'a str),
+    PrimarySetTwice(&'a str),
+    NotExhaustive(&'a str),
+    UnknownCase(&'a str),
+    VariantOnly(&'a str),
+    DefaultNotMapped(&'a str),
+    WrongAttribute(&'a str),
+    TypeNotInferred(&'a str),
+    NotAllowedWithTy(&'a str),
+    FromRangeSingleMaping,
+    RangeOverlap(&'a str),
+    MetaNotSet(&'a str),
+    MetaFieldNotSet(&'a str),
+    MetaTypeNotInferred(&'a str),
+    UnknownLookup(&'a str),
+    FeatureNotEnabled(&'a str),
+    UnitVariantsOnly(&'a str),
+    GeneratedNameCollision(&'a str),
+    UnknownMaping(&'a str),
+    NormalizationNotSupported(&'a str),
 }
 
 impl<'a> From<Error<'a>> for syn::Error {
@@ -97,6 +339,117 @@ impl<'a> std::fmt::Display for ErrorType<'a> {
             Self::DuplicateMaping(name) => {
                 write!(f, "maping with name=`{name}` set twice")
             }
+            Self::InvalidTemplate(msg) => {
+                write!(f, "invalid template: {msg}")
+            }
+            Self::UnknownField(field) => {
+                write!(f, "variant has no field `{field}`")
+            }
+            Self::AmbiguousTemplate(other) => {
+                write!(f, "template cannot be distinguished from the template of variant `{other}`")
+            }
+            Self::FieldSetTwice(field) => {
+                write!(f, "field `{field}` is set by both template and `fields`")
+            }
+            Self::FieldNotSet(field) => {
+                write!(f, "field `{field}` is not set, set it in `fields(..)` or use `fields = default`")
+            }
+            Self::TemplateNotAllowed(kw) => {
+                write!(f, "`{kw}` cannot be used on maping with values referencing fields")
+            }
+            Self::SameAfterNormalization(other) => {
+                write!(f, "value cannot be distinguished from `{other}` after normalization")
+            }
+            Self::DuplicateValue(other) => {
+                write!(f, "value is also mapped to variant `{other}`, use `allow_duplicate` if this is intended")
+            }
+            Self::PrimaryNotSet(other) => {
+                write!(
+                    f,
+                    "value is also mapped to variant `{other}`, mark one of them `primary` to be used in `from` functions"
+                )
+            }
+            Self::PrimarySetTwice(other) => {
+                write!(f, "variant `{other}` with the same value is already marked `primary`")
+            }
+            Self::NotExhaustive(unmapped) => {
+                write!(f, "maping is not exhaustive, unmapped variants: {unmapped}")
+            }
+            Self::UnknownCase(case) => {
+                let expected = RenameRule::ALL
+                    .iter()
+                    .map(|(name, _)| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "unknown case `{case}`, expected one of: {expected}")
+            }
+            Self::UnknownLookup(lookup) => {
+                let expected = Lookup::ALL
+                    .iter()
+                    .map(|(name, _)| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "unknown lookup `{lookup}`, expected one of: {expected}")
+            }
+            Self::FeatureNotEnabled(feature) => {
+                write!(f, "this argument requires `{feature}` feature of `enum_map` to be enabled")
+            }
+            Self::UnitVariantsOnly(kw) => {
+                write!(f, "argument `{kw}` can only be used on enums with only unit variants")
+            }
+            Self::NormalizationNotSupported(kw) => {
+                write!(f, "`{kw}` cannot be used with `trim`, `ignore_case` or `ignore_chars`")
+            }
+            Self::UnknownMaping(name) => {
+                write!(f, "unknown maping `{name}`")
+            }
+            Self::GeneratedNameCollision(name) => {
+                write!(f, "generated item `{name}` is already created by another maping")
+            }
+            Self::VariantOnly(kw) => {
+                write!(f, "argument `{kw}` can only be used in `mapstr` attribute on variants")
+            }
+            Self::DefaultNotMapped(variant) => {
+                write!(f, "default variant `{variant}` is not mapped")
+            }
+            Self::WrongAttribute(attr) => {
+                write!(f, "values of this maping must be set with `{attr}`")
+            }
+            Self::TypeNotInferred(name) => {
+                write!(
+                    f,
+                    "type of maping `{name}` cannot be inferred, use suffixed literal or declare it with \
+                    `#[enum_map({name}(ty = ..))]`"
+                )
+            }
+            Self::NotAllowedWithTy(kw) => {
+                write!(f, "argument `{kw}` cannot be used on maping with `ty`")
+            }
+            Self::FromRangeSingleMaping => {
+                write!(f, "`from_range` can only be used in `mapval` that sets value of single maping")
+            }
+            Self::RangeOverlap(other) => {
+                write!(f, "values overlap with values of variant `{other}` in `from` functions")
+            }
+            Self::MetaNotSet(variants) => {
+                write!(f, "`mapmeta` is not set on variants: {variants}")
+            }
+            Self::MetaFieldNotSet(field) => {
+                write!(f, "metadata field `{field}` is not set on this variant")
+            }
+            Self::MetaTypeNotInferred(field) => {
+                write!(
+                    f,
+                    "type of metadata field `{field}` cannot be inferred, use suffixed literal or declare it with \
+                    `#[mapmeta({field}: ..)]` on the enum"
+                )
+            }
+            Self::ExpectedVariant => {
+                write!(f, "expected variant, e.g. `Unknown`, `Other(String::new())` or `Retry {{ attempts: 3 }}`")
+            }
+            Self::AdjacentFields => {
+                write!(f, "fields must be separated by literal text to be parsed in `from` functions, use `no_from` to skip them")
+            }
         }
     }
 }
@@ -45,9 +45,12 @@
 //! ```
 
 use proc_macro::TokenStream;
+mod case;
 mod enum_map;
 
 mod helpers;
+mod lookup;
+mod template;
 
 /// # Macro to derive custom mapings for enum types.
 /// It provides function implementations for `to` and `from` functions for enum.
@@ -64,26 +67,376 @@ mod helpers;
 /// * `fn from_<name>(s: &str) -> Self`.
 ///
 /// First set of functions can still be then created be passing argument `try` to `mapstr` attribute.
+///
+/// If every variant is mapped, `fn to_<name>(&self) -> &'static str` is also created without needing `default_to`.
+/// Unmapped variants cannot be matched so adding a new variant without mapping it removes that function.
+/// Use `exhaustive` keyword to turn that into a compile error instead.
+///
+/// Mapings with `tables` keyword also get a constant `<NAME>_STRS: &'static [&'static str]` with all of its literal
+/// values and `fn <name>_pairs() -> impl Iterator<Item = (Self, &'static str)>` over the variants that can be created
+/// from literal values. Enums with `#[enum_map(tables)]` get `VARIANT_COUNT: usize`, if they have only unit variants
+/// also `ALL: &'static [Self]` with every variant in declaration order.
+/// ```rust
+/// use enum_map::EnumMap;
+/// #[derive(EnumMap, Debug, PartialEq)]
+/// #[enum_map(tables)]
+/// enum Example {
+///     #[mapstr("variant_1", name = "vname", tables)]
+///     V1,
+///     #[mapstr("variant_2")]
+///     V2,
+/// }
+/// assert_eq!(Example::VNAME_STRS, &["variant_1", "variant_2"]);
+/// assert_eq!(Example::vname_pairs().next(), Some((Example::V1, "variant_1")));
+/// assert_eq!(Example::VARIANT_COUNT, 2);
+/// assert_eq!(Example::ALL, &[Example::V1, Example::V2]);
+/// ```
+///
+/// `to` functions are `const fn` unless values reference fields, value mapings only if every value is a literal.
+/// Mapings with `const_from` keyword also get `fn try_from_<name>_const(s: &str) -> Option<Self>` that can be used in
+/// const context. It compares bytes of literal values of unit variants, so templates and variants with fields are not
+/// matched. It cannot be used with `trim`, `ignore_case` or `ignore_chars`, `ignore_ascii_case` is supported.
+/// ```rust
+/// use enum_map::EnumMap;
+/// #[derive(EnumMap, Debug, PartialEq)]
+/// enum Example {
+///     #[mapstr("variant_1", name = "vname", const_from)]
+///     V1,
+///     #[mapstr("variant_2")]
+///     V2,
+/// }
+/// const DEFAULT: Example = match Example::try_from_vname_const("variant_1") {
+///     Some(v) => v,
+///     None => panic!("unknown variant"),
+/// };
+/// const NAME: &str = DEFAULT.to_vname();
+/// assert_eq!(NAME, "variant_1");
+/// ```
+///
+/// Mapings with `parse_prefix` keyword also get `fn parse_prefix_<name>(s: &str) -> Option<(Self, &str)>` that finds
+/// the longest literal value that is a prefix of `s` and returns the variant with the rest of the input. It's a trie
+/// generated at compile time over literal values of the variants that `from` functions can construct, templates are
+/// not matched. It cannot be used with `trim`, `ignore_case` or `ignore_chars`.
+/// ```rust
+/// use enum_map::EnumMap;
+/// #[derive(EnumMap, Debug, PartialEq)]
+/// enum Op {
+///     #[mapstr("<", name = "sym", parse_prefix)]
+///     Lt,
+///     #[mapstr("<=")]
+///     Le,
+/// }
+/// assert_eq!(Op::parse_prefix_sym("<=x"), Some((Op::Le, "x")));
+/// assert_eq!(Op::parse_prefix_sym("<x"), Some((Op::Lt, "x")));
+/// assert_eq!(Op::parse_prefix_sym("x"), None);
+/// ```
+///
+/// Generic enums (lifetimes, type and const parameters, where clauses) are supported,
+/// all generated impls carry the same generics as the enum.
+/// # Enum attributes
+/// * `enum_map(<name>(opts..), mapping(name = "..", opts..), ..)` - declare mapings up front. Declared mapings don't
+///   depend on the order of `mapstr` attributes on variants and their values can be set with
+///   `mapstr(<name> = "..", ..)`.
+///     - `name=".."` : *string literal* - name of the maping if `mapping(..)` form is used.
+///     - `ty = ..` : *type* - map variants to values of given type, see [Value mapings](#value-mapings).
+///     - `rename_all=".."` : *string literal* - map every variant that is not mapped explicitly to its name in given
+///       case. One of `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`, `"snake_case"`,
+///       `"SCREAMING_SNAKE_CASE"`, `"kebab-case"`, `"SCREAMING-KEBAB-CASE"` or `"Title Case"`. Optional.
+///     - `default = ..` : *variant* - set default variant, same as `default` keyword on the variant. Variants with
+///       fields must be given with field values, e.g. `default = Other(String::new())`.
+///     - any of the `mapstr` options that apply to the whole maping: `default_to`, `default_from`, `try`, `no_to`,
+///       `no_from`, `display`, `from_str`, `try_from_str`, `into_str`, `as_ref`, `exhaustive`, `allow_duplicate`,
+///       `trim`, `ignore_case`, `ignore_ascii_case`, `ignore_chars`, `lit_macro`, `lookup`,
+///       `serde`, `serde_with`, `clap`, `sql`, `sql_ddl`, `const_from`, `parse_prefix`
+///       and `tables`.
+///
+///   Declared mapings come before mapings found on variants, so `mapstr` without `name` refers to them first.
+/// * `enum_map(tables)` - create `VARIANT_COUNT` constant and `ALL` constant if the enum has only unit variants. Can be
+///   combined with maping declarations, e.g. `enum_map(tables, vname(display))`.
+/// ```rust
+/// use enum_map::EnumMap;
+/// #[derive(EnumMap, Debug, PartialEq)]
+/// #[enum_map(vname(default = Unknown, display), short(r#try))]
+/// enum Example {
+///     #[mapstr(vname = "variant_1", short = "V1")]
+///     V1,
+///     #[mapstr(vname = "unknown")]
+///     Unknown,
+/// }
+/// assert_eq!(Example::V1.to_vname(), "variant_1");
+/// assert_eq!(Example::from_vname("random"), Example::Unknown);
+/// assert_eq!(Example::Unknown.try_to_short(), None);
+/// ```
+/// ```rust
+/// use enum_map::EnumMap;
+/// #[derive(EnumMap, Debug, PartialEq)]
+/// #[enum_map(mapping(name = "snake", rename_all = "snake_case"))]
+/// enum Example {
+///     HttpServer,
+///     #[mapstr("variant_2")]
+///     V2,
+/// }
+/// assert_eq!(Example::HttpServer.to_snake(), "http_server");
+/// assert_eq!(Example::V2.to_snake(), "variant_2");
+/// assert_eq!(Example::try_from_snake("http_server"), Some(Example::HttpServer));
+/// ```
 /// # Variant attributes
+/// * `mapstr(<name> = "..", ..)` - set values of declared mapings, see [Enum attributes](#enum-attributes).
 /// * `mapstr(<value> [,opts])`
-///     - `value`: *string literal* - string to map to
-///     - `name=".."` : *string literal* - set created function name as `[try]_to_<name>` `[try]_from_<fame>`. Must be set on /// first variant part of the maping.
+///     - `value`: *string literal* - string to map to. Can reference variant fields, see [Field values](#field-values).
+///     - `name=".."` : *string literal* - set created function name as `[try]_to_<name>` `[try]_from_<fame>`. Must be set on /// first variant part of the maping unless the maping is declared in `enum_map`.
 ///     - `default_to=".."` : *string literal* - set default string to map to. Optional. If set result function will return /// directly `&str` and remove "try" from the function name.
-///     - `default_from=..` : *identifier* - set default variant to map to. Optional. If set result function will return directly /// `Self` and remove "try" from the function name.
+///     - `default_from=..` : *variant* - set default variant to map to. Optional. If set result function will return directly /// `Self` and remove "try" from the function name.
+///       Variants with fields must be given with field values, e.g. `default_from = Other(String::new())`.
 ///     - `default` : *optional keyword* - set variant as default. Optional. If set resulting functions wreturn directly `&str` /// and `Self` and remove "try" from the name. Arguments `default_to/from` take precedence over this keyword.
 ///     - `try` : *optional keyword* - if set create functions returning [`Option`](_) even if defaults are set.
 ///     - `no_to` : *optional keyword* - if set don't create `to` methods.
 ///     - `no_from` : *optional keyword* - if set don't create `from` methods.
 ///     - `display` : *optional keyword* - create implementation for [`Display`](std::fmt::Display) trait. It can only be present /// on one maping set. If default is not set then default display is `"Unknown variant"`.
+///     - `from_str` : *optional keyword* - create implementation for [`FromStr`](std::str::FromStr) trait using this maping.
+///       It can only be present on one maping set. Error type is generated as `Parse<Enum>Error` with fields `input`,
+///       `mapping` and `expected`. If `default_from` is set parsing never fails.
+///     - `try_from_str` : *optional keyword* - implement `TryFrom<&str>` for the enum with `Parse<Enum>Error` as error type.
+///       If `default_from` is set, `From<&str>` is implemented instead.
+///     - `into_str` : *optional keyword* - implement `TryFrom<Enum> for &'static str` returning the value back as error
+///       if variant is not mapped. If `default_to` is set or every variant is mapped,
+///       `From<Enum> for &'static str` is implemented instead.
+///     - `as_ref` : *optional keyword* - implement `AsRef<str>` for the enum. Requires `default_to` to be set
+///       or every variant to be mapped.
+///
+///     - `serde` : *optional keyword* - implement `serde::Serialize` and `serde::Deserialize` for the enum. Requires
+///       `serde` feature of this crate, the crate using the enum must depend on `serde`. Unmapped variants are
+///       serialized as `default_to` or fail if it's not set. Unknown strings are deserialized to `default_from` if
+///       it's set, otherwise error lists expected values.
+///     - `serde_with` : *optional keyword* - create module `<enum>_<name>_serde`, where `<enum>` is the enum name in
+///       snake case, with `serialize` and `deserialize` functions for `#[serde(with = "..")]` and submodules `option`
+///       and `vec` for `Option<Enum>` and `Vec<Enum>` fields. Any number of mapings can use it, so different fields
+///       can use different mapings. Requires `serde` feature. The enum must be declared at module level, not inside
+///       a function.
+///       ```rust ignore
+///       #[derive(EnumMap)]
+///       #[enum_map(short(serde_with), vname(serde_with))]
+///       enum Example {
+///           #[mapstr(short = "v1", vname = "variant_1")]
+///           V1,
+///       }
+///       #[derive(Serialize, Deserialize)]
+///       struct Config {
+///           #[serde(with = "example_short_serde")]
+///           compact: Example,
+///           #[serde(with = "example_vname_serde::vec")]
+///           all: Vec<Example>,
+///       }
+///       ```
+///     - `clap` : *optional keyword* - implement `clap::ValueEnum` for the enum. Requires `clap` feature of this crate
+///       and the enum to have only unit variants and implement [`Clone`]. Mapped values are the possible values,
+///       aliases are hidden aliases and doc comments of the variants are help texts. Unmapped variants are not
+///       accepted.
+///     - `sql` : *optional keyword* - implement `rusqlite::types::ToSql` and `rusqlite::types::FromSql` for the enum,
+///       values are stored as text. Requires `rusqlite` feature of this crate. Unmapped variants fail with
+///       `ToSqlConversionFailure` unless `default_to` is set. Unknown strings are read as `default_from` if it's set,
+///       otherwise they fail with `FromSqlError::Other` containing `Parse<Enum>Error`.
+///     - `sql_ddl` : *optional keyword* - create SQL statements that list every value `to` functions can return,
+///       including `default_to`:
+///         - `<NAME>_SQL_CHECK: &'static str` - check constraint for column named after the maping,
+///         - `fn <name>_sql_check(column: &str) -> String` - check constraint for given column,
+///         - `<NAME>_PG_ENUM_DDL: &'static str` - creates PostgreSQL enum type `<enum>_<name>`, where `<enum>` is
+///           the enum name in snake case.
+///
+///       Doesn't require any features. Cannot be used if mapped values reference fields. Names of mapings with
+///       `sql_ddl` must differ by more than case.
+///       ```rust
+///       use enum_map::EnumMap;
+///       #[derive(EnumMap)]
+///       enum Example {
+///           #[mapstr("variant_1", name = "vname", sql_ddl)]
+///           V1,
+///           #[mapstr("variant's")]
+///           V2,
+///       }
+///       assert_eq!(Example::VNAME_SQL_CHECK, r#"CHECK ("vname" IN ('variant_1', 'variant''s'))"#);
+///       assert_eq!(Example::vname_sql_check("col"), r#"CHECK ("col" IN ('variant_1', 'variant''s'))"#);
+///       assert_eq!(
+///           Example::VNAME_PG_ENUM_DDL,
+///           r#"CREATE TYPE "example_vname" AS ENUM ('variant_1', 'variant''s')"#
+///       );
+///       ```
+///
+///       Each of the traits can only be implemented by one maping set. `into_str` and `as_ref` cannot be used if
+///       mapped values reference fields.
+///     - `fields = default` : *optional* - construct variant in `from` functions by setting fields that are not
+///       referenced in `value` to [`Default::default()`].
+///     - `fields(0 = expr, name = expr)` : *optional* - construct variant in `from` functions by setting fields that
+///       are not referenced in `value` to given expressions. All of the remaining fields must be set.
+///     - `aliases("..", "..")` : *string literals* - additional values accepted by `from` functions. `to` functions
+///       and [`Display`](std::fmt::Display) use only `value`. Aliases can reference fields like `value` but each alias
+///       must be able to construct the variant by itself. If any variant of the maping has aliases,
+///       `fn <name>_aliases(&self) -> &'static [&'static str]` is created to query them.
+///     - `exhaustive` : *optional keyword* - require every variant to be mapped, compile error lists unmapped variants.
+///     - `const_from` : *optional keyword* - create `fn try_from_<name>_const(s: &str)`, see above.
+///     - `parse_prefix` : *optional keyword* - create `fn parse_prefix_<name>(s: &str)`, see above.
+///     - `tables` : *optional keyword* - create `<NAME>_STRS` constant and `fn <name>_pairs()`. Names of mapings with
+///       `tables` must differ by more than case.
+///     - `lit_macro` : *optional keyword* - create macro `<enum>_<name>!("..")`, where `<enum>` is the enum name in
+///       snake case. It expands to the variant mapped to the given string literal and fails to compile with the list
+///       of valid values on unknown literals. Only exact literal values of variants that `from` functions can create
+///       are accepted. The macro can be used after the enum in the same module like any `macro_rules!` macro, or by
+///       path like `module::<enum>_<name>!("..")` from other modules of the crate. It expands to `<Enum>::<Variant>`,
+///       so the enum must be in scope where the macro is called.
+///       ```rust
+///       use enum_map::EnumMap;
+///       #[derive(EnumMap, Debug, PartialEq)]
+///       enum Example {
+///           #[mapstr("variant_1", name = "vname", lit_macro)]
+///           V1,
+///           #[mapstr("variant_2")]
+///           V2,
+///       }
+///       assert_eq!(example_vname!("variant_2"), Example::V2);
+///       ```
+///       ```compile_fail
+///       # use enum_map::EnumMap;
+///       # #[derive(EnumMap, Debug, PartialEq)]
+///       # enum Example {
+///       #     #[mapstr("variant_1", name = "vname", lit_macro)]
+///       #     V1,
+///       # }
+///       let v = example_vname!("varaint_1");
+///       ```
+///     - `allow_duplicate` : *optional keyword* - allow multiple variants to map to the same value. Mapped values must
+///       otherwise be unique within a maping. If such variants are parsed by `from` functions, one of them must be
+///       marked `primary`.
+///     - `primary` : *optional keyword* - use this variant in `from` functions if other variants map to the same value.
+///     - `trim` : *optional keyword* - ignore leading and trailing whitespace in `from` functions.
+///     - `ignore_case` : *optional keyword* - match case-insensitively in `from` functions, uses [`str::to_lowercase`].
+///     - `ignore_ascii_case` : *optional keyword* - match ASCII case-insensitively in `from` functions.
+///     - `ignore_chars = ".."` : *string literal* - ignore given characters in `from` functions, e.g. `"-_ "`.
+///
+///       Normalization options apply to the whole maping and are combined from all variants. Both the input and
+///       mapped values are normalized before comparing, `to` functions still return values as written. Values that
+///       are equal after normalization are a compile error.
+///     - `lookup = ".."` : *string literal* - strategy used to find exact matches in `from` functions:
+///         - `"match"` - match on strings directly. Default for mapings with up to 32 exact values.
+///         - `"tree"` - dispatch on length and then on single bytes. Default for larger mapings.
+///         - `"phf"` - perfect hash table generated at compile time, one hash and one string comparison per lookup.
+///           Falls back to `"tree"` if no hash table is found at compile time.
+///
+///       Templates are tried after exact matches with every strategy. Benchmarks are in `benches/lookup.rs`.
 ///
 /// Optional arguments can be specified on any of the variants but only the first specification is used.
-/// # Current shortcomings
-/// * Variants with fields have limited support. They cannot be created with `frfunctions and in `to` functions the field values /// are currently ignored.
-///   If maping is applied to an enum which variants have field then `to` function ignofield values.
-///   `From` function must return default or `None` instead of variant with fields asdon't really know what to provide in those /// fields.
-///   I suppose if all variants have the same field we could create function with exparameters but if there are many different
-///   types stored in variants then every single one of them would need to be in function signature and that's not reasonable /// thing to do.
-///  
+/// * `mapval(<name> = <expr>, ..)` - map variant to value of other type than string, see [Value mapings](#value-mapings).
+/// * `mapmeta(<field> = <expr>, ..)` - set metadata record of the variant, see [Metadata](#metadata).
+/// # Value mapings
+/// Variants can be mapped to values of any type implementing [`PartialEq`], e.g. integers, chars, byte strings or
+/// constants. Type of the maping is declared with `#[enum_map(<name>(ty = ..))]` or inferred from suffixed literal
+/// like `404u16`, `'c'`, `b"tag"` or `"str"`. Created functions are
+/// * `fn [try_]to_<name>(&self) -> [Option<]ty[>]`,
+/// * `fn [try_]from_<name>(v: ty) -> [Option<]Self[>]`,
+///
+/// with the same rules about defaults, `try`, `no_to`, `no_from` and `exhaustive` as string mapings. Defaults can only
+/// be set in declaration with `default = Variant` or `default_from = ..`. Only unit variants can be created by `from`
+/// functions.
+/// ```rust
+/// use enum_map::EnumMap;
+/// #[derive(EnumMap, Debug, PartialEq)]
+/// #[enum_map(code(ty = u16, default = Unknown))]
+/// enum Example {
+///     #[mapval(code = 404, flag = 'n')]
+///     NotFound,
+///     #[mapval(code = 0)]
+///     Unknown,
+/// }
+/// assert_eq!(Example::NotFound.to_code(), 404);
+/// assert_eq!(Example::from_code(1), Example::Unknown);
+/// assert_eq!(Example::try_from_flag('n'), Some(Example::NotFound));
+/// ```
+///
+/// Variant can accept a range or a set of values in `from` functions with `from_range = 500..=599` or
+/// `from_range = [401, 403, 500..600]`. The mapped value is still used in `to` functions. `from_range` can only be used
+/// in `mapval` attribute that sets single maping. Values of different variants cannot overlap unless
+/// `allow_duplicate` is set, overlaps are detected for integer literals.
+/// ```rust
+/// use enum_map::EnumMap;
+/// #[derive(EnumMap, Debug, PartialEq)]
+/// #[enum_map(code(ty = u16))]
+/// enum Status {
+///     #[mapval(code = 200, from_range = 200..300)]
+///     Success,
+///     #[mapval(code = 503)]
+///     Unavailable,
+///     #[mapval(code = 500, from_range = [500..503, 504..=599])]
+///     ServerError,
+/// }
+/// assert_eq!(Status::try_from_code(204), Some(Status::Success));
+/// assert_eq!(Status::try_from_code(503), Some(Status::Unavailable));
+/// assert_eq!(Status::try_from_code(599), Some(Status::ServerError));
+/// assert_eq!(Status::ServerError.to_code(), 500);
+/// assert_eq!(Status::try_from_code(404), None);
+/// ```
+/// # Metadata
+/// Multiple values can be grouped into one record per variant with `#[mapmeta(<field> = <expr>, ..)]`. This creates a
+/// struct `<Enum>Meta` with the given fields and function `fn meta(&self) -> &'static <Enum>Meta` that returns the
+/// record from a static table. Every variant must set every field and values must be usable in statics. Field types
+/// are inferred from suffixed literals like for value mapings or declared on the enum with
+/// `#[mapmeta(<field>: <ty>, ..)]`.
+/// ```rust
+/// use enum_map::EnumMap;
+/// #[derive(EnumMap)]
+/// #[mapmeta(code: u16)]
+/// enum ErrorCode {
+///     #[mapmeta(code = 7, label = "Timeout", retryable = true)]
+///     Timeout,
+///     #[mapmeta(code = 9, label = "Invalid input", retryable = false)]
+///     InvalidInput(String),
+/// }
+/// let meta = ErrorCode::Timeout.meta();
+/// assert_eq!(meta.code, 7);
+/// assert_eq!(meta.label, "Timeout");
+/// assert!(!ErrorCode::InvalidInput(String::new()).meta().retryable);
+/// ```
+/// # Field values
+/// Mapped value can reference variant fields, `{0}` for tuple variants and `{name}` for struct variants.
+/// Use `{{` and `}}` to insert literal braces. Referenced fields must implement [`Display`](std::fmt::Display).
+/// If any value of the maping references fields, `to` functions return `Cow<'static, str>` instead of `&'static str`.
+/// [`Display`](std::fmt::Display) implementation uses the same templates.
+///
+/// If template references every field of the variant exactly once, `from` functions can construct the variant.
+/// Literal parts of the template are matched and fields are parsed with [`FromStr`](std::str::FromStr).
+/// Field takes everything up to the first occurrence of following literal text, so fields must be separated by
+/// literal text. Exact matches are tried first, then templates in declaration order. If a field fails to parse, next
+/// template is tried. Templates with the same literal parts cannot be told apart and are a compile error.
+/// ```rust
+/// use enum_map::EnumMap;
+/// #[derive(EnumMap, Debug, PartialEq)]
+/// enum Example {
+///     #[mapstr("port_{0}", name = "vname", display)]
+///     Port(u16),
+///     #[mapstr("{host}:{port}")]
+///     Addr { host: String, port: u16 },
+/// }
+/// assert_eq!(Example::Port(80).to_vname(), "port_80");
+/// assert_eq!(Example::Addr { host: "localhost".into(), port: 80 }.to_string(), "localhost:80");
+/// assert_eq!(Example::try_from_vname("port_80"), Some(Example::Port(80)));
+/// ```
+///
+/// Variants with fields can also be constructed if values for the fields not referenced in the template are given by
+/// `fields` argument.
+/// ```rust
+/// use enum_map::EnumMap;
+/// #[derive(EnumMap, Debug, PartialEq)]
+/// enum Example {
+///     #[mapstr("retry", name = "vname", fields(attempts = 3))]
+///     Retry { attempts: u8 },
+///     #[mapstr("wait_{0}", fields = default)]
+///     Wait(u32, Option<String>),
+///     #[mapstr("other", default_from = Other(String::new()))]
+///     Other(String),
+/// }
+/// assert_eq!(Example::from_vname("retry"), Example::Retry { attempts: 3 });
+/// assert_eq!(Example::from_vname("wait_10"), Example::Wait(10, None));
+/// assert_eq!(Example::from_vname("random"), Example::Other(String::new()));
+/// ```
+///
 /// # Examples
 /// Simplest form with default function names
 /// ```rust
@@ -105,22 +458,24 @@ mod helpers;
 /// This example expands to
 /// ```rust ignore
 /// impl Example {
-///     fn to_vname(&self) -> &'static str {
+///     const fn to_vname(&self) -> &'static str {
 ///         match self {
 ///             Self::V1 => "variant_1",
 ///             Self::V2 => "variant_2",
-///             _ => "unknown"
+///             Self::Unknown => "unknown",
 ///         }
 ///     }
 ///     fn from_vname(s: &str) -> Self {
 ///         match s {
-///             s if s == "variant_1" => Self::V1,
-///             s if s == "variant_2"  => Self::V2,
-///             _ => Self::Unknown   
+///             "variant_1" => Self::V1,
+///             "variant_2" => Self::V2,
+///             "unknown" => Self::Unknown,
+///             __s => Self::Unknown,
 ///         }
 ///     }
 /// }
 /// ```
+/// Mapings with more values match the input with a tree or a perfect hash table instead, see `lookup` above.
 /// Following shows different options.
 /// ```rust
 /// use enum_map::EnumMap;
@@ -162,7 +517,7 @@ mod helpers;
 /// assert_eq!(Example::try_from_pretty("Variant 3"), Some(Example::V3));
 /// assert_eq!(Example::try_from_pretty("unknown"), None);
 /// ```
-#[proc_macro_derive(EnumMap, attributes(mapstr))]
+#[proc_macro_derive(EnumMap, attributes(mapstr, mapval, mapmeta, enum_map))]
 pub fn enum_map(item: TokenStream) -> TokenStream {
     enum_map::enum_map(item)
 }
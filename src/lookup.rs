@@ -0,0 +1,316 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// Strategy used to find exact matches in `from` functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Lookup {
+    /// `match s { "a" => .., }`
+    Match,
+    /// Dispatch on length and then on single bytes until one candidate is left
+    Tree,
+    /// Perfect hash table built at compile time
+    Phf,
+}
+
+impl Lookup {
+    /// All supported strategies as they are written in `lookup`
+    pub(crate) const ALL: &'static [(&'static str, Self)] = &[
+        ("match", Self::Match),
+        ("tree", Self::Tree),
+        ("phf", Self::Phf),
+    ];
+
+    /// Mapings with more exact values than this use `Tree` if `lookup` is not set
+    const TREE_THRESHOLD: usize = 32;
+
+    pub(crate) fn from_str(s: &str) -> Option<Self> {
+        Self::ALL.iter().find(|(name, _)| *name == s).map(|(_, l)| *l)
+    }
+
+    /// Strategy used if `lookup` is not set
+    pub(crate) fn default_for(len: usize) -> Self {
+        if len > Self::TREE_THRESHOLD {
+            Self::Tree
+        } else {
+            Self::Match
+        }
+    }
+
+    /// Expression that evaluates to `Option<usize>`, index of the key equal to `input`. Keys must be unique.
+    pub(crate) fn index(&self, input: &TokenStream, keys: &[String]) -> TokenStream {
+        match self {
+            Self::Match => {
+                let idx = 0..keys.len();
+                quote! {
+                    match #input {
+                        #(#keys => ::std::option::Option::Some(#idx),)*
+                        _ => ::std::option::Option::None,
+                    }
+                }
+            }
+            Self::Tree => {
+                let keys: Vec<_> = keys.iter().enumerate().map(|(i, k)| (i, k.as_bytes())).collect();
+                let tree = tree_by_len(&keys);
+                quote! {{
+                    let b = #input.as_bytes();
+                    #tree
+                }}
+            }
+            Self::Phf => phf(input, keys),
+        }
+    }
+}
+
+/// Dispatch on length of the input, `b` is the input as bytes
+fn tree_by_len(keys: &[(usize, &[u8])]) -> TokenStream {
+    let mut lens: Vec<usize> = keys.iter().map(|(_, k)| k.len()).collect();
+    lens.sort_unstable();
+    lens.dedup();
+
+    let arms = lens.iter().map(|len| {
+        let group: Vec<_> = keys.iter().filter(|(_, k)| k.len() == *len).cloned().collect();
+        let subtree = tree_by_byte(&group);
+        quote! { #len => #subtree, }
+    });
+    quote! {
+        match b.len() {
+            #(#arms)*
+            _ => ::std::option::Option::None,
+        }
+    }
+}
+
+/// Dispatch on the byte that splits keys of the same length into most groups
+fn tree_by_byte(keys: &[(usize, &[u8])]) -> TokenStream {
+    if let [(i, key)] = keys {
+        let key = Literal::byte_string(key);
+        return quote! {
+            if b == #key {
+                ::std::option::Option::Some(#i)
+            } else {
+                ::std::option::Option::None
+            }
+        };
+    }
+
+    let distinct_at = |pos: usize| {
+        let mut bytes: Vec<u8> = keys.iter().map(|(_, k)| k[pos]).collect();
+        bytes.sort_unstable();
+        bytes.dedup();
+        bytes
+    };
+    // Keys are unique and have the same length, so some position has at least two different bytes
+    let (pos, bytes) = (0..keys[0].1.len())
+        .map(|pos| (pos, distinct_at(pos)))
+        .max_by_key(|(pos, bytes)| (bytes.len(), std::cmp::Reverse(*pos)))
+        .expect("keys with the same length must differ");
+
+    let arms = bytes.iter().map(|byte| {
+        let group: Vec<_> = keys.iter().filter(|(_, k)| k[pos] == *byte).cloned().collect();
+        let subtree = tree_by_byte(&group);
+        let byte = Literal::u8_suffixed(*byte);
+        quote! { #byte => #subtree, }
+    });
+    quote! {
+        match b[#pos] {
+            #(#arms)*
+            _ => ::std::option::Option::None,
+        }
+    }
+}
+
+/// FNV-1a with seed, must match the function in [`phf`] output
+fn hash(bytes: &[u8], seed: u64) -> u64 {
+    let mut h = 0xcbf2_9ce4_8422_2325 ^ seed;
+    for b in bytes {
+        h ^= *b as u64;
+        h = h.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    h
+}
+
+/// Final mixing step of a hash, must match the function in [`phf`] output
+fn mix(mut h: u64) -> u64 {
+    h ^= h >> 30;
+    h = h.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    h ^= h >> 27;
+    h = h.wrapping_mul(0x94d0_49bb_1331_11eb);
+    h ^ (h >> 31)
+}
+
+/// Mixed with the hash to derive second independent hash
+const SEED2: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Hashes of a key: bucket and two values displaced by the bucket's `(d1, d2)`
+fn hashes(key: &[u8], seed: u64, buckets: usize) -> (usize, u32, u32) {
+    let h = hash(key, seed);
+    let (h1, h2) = (mix(h), mix(h ^ SEED2));
+    (((h1 >> 32) % buckets as u64) as usize, h1 as u32, h2 as u32)
+}
+
+fn displace(f1: u32, f2: u32, d1: u32, d2: u32, len: usize) -> usize {
+    (f2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(d2) % len as u32) as usize
+}
+
+/// Perfect hash table of the keys
+struct HashTable {
+    seed: u64,
+    /// Displacements `(d1, d2)` for each bucket
+    disps: Vec<(u32, u32)>,
+    /// Index of the key in each slot
+    indices: Vec<usize>,
+}
+
+/// Find displacements for every bucket, `None` if some bucket cannot be placed with this seed
+fn generate(keys: &[String], seed: u64) -> Option<HashTable> {
+    const LAMBDA: usize = 5;
+    let len = keys.len();
+    let n_buckets = len.div_ceil(LAMBDA);
+
+    let hashes: Vec<_> = keys.iter().map(|k| hashes(k.as_bytes(), seed, n_buckets)).collect();
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); n_buckets];
+    for (i, (g, _, _)) in hashes.iter().enumerate() {
+        buckets[*g].push(i);
+    }
+    let mut order: Vec<usize> = (0..n_buckets).collect();
+    order.sort_by_key(|b| std::cmp::Reverse(buckets[*b].len()));
+
+    let mut disps = vec![(0, 0); n_buckets];
+    let mut slots: Vec<Option<usize>> = vec![None; len];
+    let mut taken = Vec::new();
+    for b in order {
+        let bucket = &buckets[b];
+        if bucket.is_empty() {
+            continue;
+        }
+        let found = (0..len as u32).flat_map(|d1| (0..len as u32).map(move |d2| (d1, d2))).find(|&(d1, d2)| {
+            taken.clear();
+            bucket.iter().all(|&i| {
+                let (_, f1, f2) = hashes[i];
+                let slot = displace(f1, f2, d1, d2, len);
+                if slots[slot].is_some() || taken.contains(&slot) {
+                    return false;
+                }
+                taken.push(slot);
+                true
+            })
+        })?;
+        disps[b] = found;
+        for (&i, &slot) in bucket.iter().zip(taken.iter()) {
+            slots[slot] = Some(i);
+        }
+    }
+
+    Some(HashTable {
+        seed,
+        disps,
+        indices: slots.into_iter().map(|s| s.expect("every slot is filled")).collect(),
+    })
+}
+
+/// Perfect hash lookup, tables are searched with the same hash function at runtime
+fn phf(input: &TokenStream, keys: &[String]) -> TokenStream {
+    if keys.is_empty() {
+        return quote! { ::std::option::Option::None };
+    }
+
+    // Keys whose hashes collide for every seed would never be placed
+    const MAX_SEEDS: u64 = 1024;
+    let HashTable { seed, disps, indices } = match (0..MAX_SEEDS).find_map(|seed| generate(keys, seed)) {
+        Some(table) => table,
+        None => return Lookup::Tree.index(input, keys),
+    };
+
+    let len = keys.len();
+    let n_buckets = disps.len();
+    let (d1, d2): (Vec<_>, Vec<_>) = disps.into_iter().unzip();
+    let slot_keys = indices.iter().map(|&i| &keys[i]);
+
+    quote! {{
+        fn hash(bytes: &[u8], seed: u64) -> u64 {
+            let mut h = 0xcbf2_9ce4_8422_2325u64 ^ seed;
+            for b in bytes {
+                h ^= *b as u64;
+                h = h.wrapping_mul(0x0000_0100_0000_01b3);
+            }
+            h
+        }
+
+        fn mix(mut h: u64) -> u64 {
+            h ^= h >> 30;
+            h = h.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            h ^= h >> 27;
+            h = h.wrapping_mul(0x94d0_49bb_1331_11eb);
+            h ^ (h >> 31)
+        }
+
+        static DISPS: [(u32, u32); #n_buckets] = [#((#d1, #d2)),*];
+        static INDICES: [usize; #len] = [#(#indices),*];
+        static KEYS: [&str; #len] = [#(#slot_keys),*];
+
+        let s: &str = #input;
+        let h = hash(s.as_bytes(), #seed);
+        let (h1, h2) = (mix(h), mix(h ^ #SEED2));
+        let (d1, d2) = DISPS[((h1 >> 32) % #n_buckets as u64) as usize];
+        let slot = ((h2 as u32).wrapping_add((h1 as u32).wrapping_mul(d1)).wrapping_add(d2) % #len as u32) as usize;
+        if KEYS[slot] == s {
+            ::std::option::Option::Some(INDICES[slot])
+        } else {
+            ::std::option::Option::None
+        }
+    }}
+}
+
+/// Expression that evaluates to `Option<(usize, usize)>`, index and length of the longest key that is a prefix of
+/// `input`. Keys must be unique. If `ignore_ascii_case` is set, keys must be in lowercase.
+pub(crate) fn longest_prefix(input: &TokenStream, keys: &[String], ignore_ascii_case: bool) -> TokenStream {
+    if keys.is_empty() {
+        return quote! { ::std::option::Option::None::<(usize, usize)> };
+    }
+
+    let keys: Vec<_> = keys.iter().enumerate().map(|(i, k)| (i, k.as_bytes())).collect();
+    let trie = trie(&keys, 0, ignore_ascii_case);
+    quote! {{
+        let b = #input.as_bytes();
+        let mut longest: ::std::option::Option<(usize, usize)> = ::std::option::Option::None;
+        #trie
+        longest
+    }}
+}
+
+/// Statements that record the key ending at `depth` and descend into keys continuing with the next byte
+fn trie(keys: &[(usize, &[u8])], depth: usize, ignore_ascii_case: bool) -> TokenStream {
+    let end = keys.iter().find(|(_, k)| k.len() == depth).map(|(i, _)| {
+        quote! { longest = ::std::option::Option::Some((#i, #depth)); }
+    });
+
+    let mut bytes: Vec<u8> = keys.iter().filter(|(_, k)| k.len() > depth).map(|(_, k)| k[depth]).collect();
+    bytes.sort_unstable();
+    bytes.dedup();
+    if bytes.is_empty() {
+        return quote! { #end };
+    }
+
+    let arms = bytes.iter().map(|byte| {
+        let group: Vec<_> = keys
+            .iter()
+            .filter(|(_, k)| k.len() > depth && k[depth] == *byte)
+            .cloned()
+            .collect();
+        let subtrie = trie(&group, depth + 1, ignore_ascii_case);
+        let byte = Literal::u8_suffixed(*byte);
+        quote! { ::std::option::Option::Some(#byte) => { #subtrie } }
+    });
+    let next = if ignore_ascii_case {
+        quote! { b.get(#depth).map(u8::to_ascii_lowercase) }
+    } else {
+        quote! { b.get(#depth).copied() }
+    };
+    quote! {
+        #end
+        match #next {
+            #(#arms)*
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use proc_macro2::Ident;
+
+/// Parsed mapped string like `"port_{0}"` or `"{host}:{port}"`
+#[derive(Debug, Clone)]
+pub(crate) struct Template {
+    pub(crate) source: String,
+    pub(crate) segments: Vec<Segment>,
+}
+
+/// Part of template, either literal text or reference to a variant field
+#[derive(Debug, Clone)]
+pub(crate) enum Segment {
+    Lit(String),
+    Field(FieldRef),
+}
+
+/// Reference to a variant field in `{..}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum FieldRef {
+    Index(usize),
+    Named(String),
+}
+
+impl std::fmt::Display for FieldRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Index(i) => write!(f, "{i}"),
+            Self::Named(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl Template {
+    /// Parse template string. `{{` and `}}` are escapes for literal braces.
+    pub(crate) fn parse(s: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut lit = String::new();
+        let mut chars = s.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    lit.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    lit.push('}');
+                }
+                '{' => {
+                    let mut field = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => field.push(c),
+                            None => return Err(String::from("unclosed `{`")),
+                        }
+                    }
+
+                    let field = if field.is_empty() {
+                        return Err(String::from("empty field reference `{}`"));
+                    } else if let Ok(idx) = field.parse::<usize>() {
+                        FieldRef::Index(idx)
+                    } else if syn::parse_str::<Ident>(&field).is_ok() {
+                        FieldRef::Named(field)
+                    } else {
+                        return Err(format!("invalid field reference `{{{field}}}`"));
+                    };
+
+                    if !lit.is_empty() {
+                        segments.push(Segment::Lit(std::mem::take(&mut lit)));
+                    }
+                    segments.push(Segment::Field(field));
+                }
+                '}' => return Err(String::from("unmatched `}`, use `}}` to insert literal `}`")),
+                c => lit.push(c),
+            }
+        }
+
+        if !lit.is_empty() || segments.is_empty() {
+            segments.push(Segment::Lit(lit));
+        }
+
+        Ok(Self {
+            source: String::from(s),
+            segments,
+        })
+    }
+
+    /// Template without field references
+    pub(crate) fn is_literal(&self) -> bool {
+        self.fields().next().is_none()
+    }
+
+    /// All field references in template
+    pub(crate) fn fields(&self) -> impl Iterator<Item = &FieldRef> {
+        self.segments.iter().filter_map(|s| match s {
+            Segment::Field(f) => Some(f),
+            Segment::Lit(_) => None,
+        })
+    }
+
+    /// Literal text of template with field references removed
+    pub(crate) fn literal(&self) -> String {
+        self.segments
+            .iter()
+            .filter_map(|s| match s {
+                Segment::Lit(l) => Some(l.as_str()),
+                Segment::Field(_) => None,
+            })
+            .collect()
+    }
+
+    /// Format string to be used with `format!` like macros. Fields are replaced by `{}` in order of appearance.
+    pub(crate) fn format_str(&self) -> String {
+        self.segments
+            .iter()
+            .map(|s| match s {
+                Segment::Lit(l) => l.replace('{', "{{").replace('}', "}}"),
+                Segment::Field(_) => String::from("{}"),
+            })
+            .collect()
+    }
+
+    /// Template with literal parts transformed by `f(segment_index, literal)`. Literals that become empty are
+    /// removed.
+    pub(crate) fn normalized(&self, f: impl Fn(usize, &str) -> String) -> Self {
+        let segments = self
+            .segments
+            .iter()
+            .enumerate()
+            .filter_map(|(i, s)| match s {
+                Segment::Lit(l) => {
+                    let l = f(i, l);
+                    (!l.is_empty()).then_some(Segment::Lit(l))
+                }
+                Segment::Field(field) => Some(Segment::Field(field.clone())),
+            })
+            .collect();
+        Self {
+            source: self.source.clone(),
+            segments,
+        }
+    }
+
+    /// Literal segments of template with fields replaced by `None`. Templates with the same skeleton cannot be
+    /// distinguished from each other when parsing.
+    pub(crate) fn skeleton(&self) -> Vec<Option<&str>> {
+        self.segments
+            .iter()
+            .map(|s| match s {
+                Segment::Lit(l) => Some(l.as_str()),
+                Segment::Field(_) => None,
+            })
+            .collect()
+    }
+
+    /// Two fields follow each other without literal text in between
+    pub(crate) fn has_adjacent_fields(&self) -> bool {
+        self.segments
+            .windows(2)
+            .any(|w| matches!(w, [Segment::Field(_), Segment::Field(_)]))
+    }
+}
+
+impl From<&syn::Member> for FieldRef {
+    fn from(m: &syn::Member) -> Self {
+        match m {
+            syn::Member::Named(ident) => Self::Named(ident.to_string()),
+            syn::Member::Unnamed(idx) => Self::Index(idx.index as usize),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use clap::{Arg, Command, ValueEnum};
+use enum_map::EnumMap;
+
+#[test]
+fn clap() {
+    #[derive(EnumMap, Debug, Clone, Copy, Eq, PartialEq)]
+    enum Format {
+        /// Compact output
+        #[mapstr("short", name = "short", aliases("s"), clap, exhaustive)]
+        Short,
+        /// Full output
+        /// with every field
+        #[mapstr("full")]
+        Full,
+        #[mapstr("json")]
+        Json,
+    }
+
+    assert_eq!(Format::value_variants(), &[Format::Short, Format::Full, Format::Json]);
+    let short = Format::Short.to_possible_value().unwrap();
+    assert_eq!(short.get_name(), "short");
+    assert_eq!(short.get_name_and_aliases().collect::<Vec<_>>(), ["short", "s"]);
+    assert_eq!(short.get_help().unwrap().to_string(), "Compact output");
+    let full = Format::Full.to_possible_value().unwrap();
+    assert_eq!(full.get_help().unwrap().to_string(), "Full output with every field");
+    assert!(Format::Json.to_possible_value().unwrap().get_help().is_none());
+    assert_eq!(<Format as ValueEnum>::from_str("FULL", true), Ok(Format::Full));
+    assert_eq!(Format::Short.to_short(), "short");
+
+    let cmd = Command::new("cli").arg(
+        Arg::new("format")
+            .long("format")
+            .value_parser(clap::value_parser!(Format)),
+    );
+    let matches = cmd.clone().try_get_matches_from(["cli", "--format", "short"]).unwrap();
+    assert_eq!(matches.get_one::<Format>("format"), Some(&Format::Short));
+    let matches = cmd.clone().try_get_matches_from(["cli", "--format", "s"]).unwrap();
+    assert_eq!(matches.get_one::<Format>("format"), Some(&Format::Short));
+    assert!(cmd.try_get_matches_from(["cli", "--format", "xml"]).is_err());
+
+    #[derive(EnumMap, Debug, Clone, Eq, PartialEq)]
+    enum Level {
+        #[mapstr("debug", name = "level", clap)]
+        Debug,
+        #[mapstr("info")]
+        Info,
+        Trace,
+    }
+
+    assert_eq!(Level::value_variants(), &[Level::Debug, Level::Info]);
+    assert!(Level::Trace.to_possible_value().is_none());
+    assert_eq!(Level::Info.to_possible_value().unwrap().get_name(), "info");
+    assert_eq!(<Level as ValueEnum>::from_str("trace", false).ok(), None);
+    assert_eq!(Level::Debug.try_to_level(), Some("debug"));
+}
@@ -94,4 +94,216 @@ enum Ex9 {
     V1,
 }
 #[derive(EnumMap)]
-struct Sa {}
\ No newline at end of file
+struct Sa {}
+#[derive(EnumMap)]
+enum Ex10 {
+    #[mapstr("port_{1}", name="n")]
+    V1(u8),
+    #[mapstr("{host}")]
+    V2 { port: u8 },
+    #[mapstr("{0}")]
+    V3,
+    #[mapstr("port_{0")]
+    V4(u8),
+    #[mapstr("port}")]
+    V5,
+}
+
+#[derive(EnumMap)]
+enum Ex11 {
+    #[mapstr("port_{0}", name="n")]
+    V1(u8),
+    #[mapstr("port_{0}")]
+    V2(String),
+    #[mapstr("port_{x}")]
+    V5 { x: u8 },
+    #[mapstr("{0}{1}")]
+    V3(u8, u8),
+    #[mapstr("{0}{1}", name="n2", no_from)]
+    V4(u8, u8),
+}
+
+#[derive(EnumMap)]
+enum Ex12 {
+    #[mapstr("v1", name="n", fields(attempts = 1))]
+    V1 { attempts: u8, delay: u32 },
+    #[mapstr("v2_{0}", fields(0 = 1))]
+    V2(u8),
+    #[mapstr("v3", fields(x = 1))]
+    V3(u8),
+    #[mapstr("v4", default_from = Other::new())]
+    V4,
+}
+
+#[derive(EnumMap)]
+enum Ex13 {
+    #[mapstr("a", name = "n", from_str)]
+    #[mapstr("b", name = "n2", from_str)]
+    V1,
+}
+
+#[derive(EnumMap)]
+enum Ex14 {
+    #[mapstr("a", name = "n", as_ref)]
+    #[mapstr("a_{0}", name = "n2", default_to = "b", into_str)]
+    #[mapstr("a", name = "n3", try_from_str)]
+    V1(u8),
+    #[mapstr("b", name = "n3", try_from_str)]
+    #[mapstr("b", name = "n4", try_from_str)]
+    V2,
+}
+
+#[derive(EnumMap)]
+enum Ex15 {
+    #[mapstr("Kilo-Byte", name = "n", ignore_case, ignore_chars = "-")]
+    V1,
+    #[mapstr("kilobyte")]
+    V2,
+    #[mapstr("a {0}-{1}")]
+    V3(u8, u8),
+}
+
+#[derive(EnumMap)]
+enum Ex16 {
+    #[mapstr("a_{0}", name = "n", aliases("a", "b_{1}"))]
+    V1(u8),
+    #[mapstr("v2", aliases("v2_{x}"))]
+    V2 { y: u8 },
+}
+
+#[derive(EnumMap)]
+enum Ex17 {
+    #[mapstr("a", name = "n")]
+    #[mapstr("a", name = "n2", allow_duplicate)]
+    #[mapstr("a", name = "n3", allow_duplicate, primary)]
+    V1,
+    #[mapstr("a")]
+    #[mapstr("a")]
+    #[mapstr("a", primary)]
+    V2,
+}
+
+#[derive(EnumMap)]
+enum Ex18 {
+    #[mapstr("a", name = "n", exhaustive)]
+    V1,
+    V2,
+    V3(u8),
+}
+
+#[derive(EnumMap)]
+#[enum_map(mapping(name = "a", rename_all = "snake"))]
+#[enum_map(mapping(rename_all = "snake_case"))]
+enum Ex19 {
+    V1,
+}
+
+#[derive(EnumMap)]
+#[enum_map(b(aliases("x")))]
+#[enum_map(a(default = V2), c(display))]
+#[enum_map(d(display), a(r#try))]
+enum Ex20 {
+    #[mapstr(a = "v1", e = "v1")]
+    V1,
+    V2,
+}
+
+#[derive(EnumMap)]
+#[enum_map(a(ty = u8, display))]
+#[enum_map(b(ty = u8, exhaustive))]
+enum Ex21 {
+    #[mapval(b = 1, c = 1)]
+    #[mapstr("v1", name = "b")]
+    V1,
+    #[mapval(b = 1, d = 1u8)]
+    V2,
+}
+
+#[derive(EnumMap)]
+#[enum_map(a(ty = u16), b(ty = u16))]
+enum Ex22 {
+    #[mapval(a = 500, from_range = 500..=599)]
+    V1,
+    #[mapval(a = 503)]
+    V2,
+    #[mapval(a = 1, from_range = [1, 2..5, 599..])]
+    V3,
+    #[mapval(a = 10, b = 10, from_range = 10..20)]
+    V4,
+    #[mapval(a = 11, from_range = 10..20, from_range = 10..20)]
+    V5,
+}
+
+#[derive(EnumMap)]
+#[mapmeta(a: u8, a: u16)]
+enum Ex23 {
+    #[mapmeta(b = 1, c = "c")]
+    V1,
+    #[mapmeta(c = "d", c = "e")]
+    V2,
+    V3,
+}
+
+#[derive(EnumMap)]
+enum Ex24 {
+    #[mapstr("a", name = "n", lit_macro)]
+    V1,
+    #[mapstr("v2_{0}")]
+    V2(u8),
+}
+
+#[derive(EnumMap)]
+enum Ex25 {
+    #[mapstr("a", name = "n", lookup = "hash")]
+    V1,
+}
+
+#[derive(EnumMap)]
+#[enum_map(n(tables), N(tables))]
+enum Ex26 {
+    #[mapstr(n = "a", N = "A")]
+    V1,
+}
+
+#[derive(EnumMap)]
+#[enum_map(vname())]
+enum Ex27 {
+    #[mapstr(vname = "red", shrt = "R")]
+    Red,
+}
+
+#[derive(EnumMap)]
+#[enum_map(code(ty = u16), ch(ty = char))]
+enum Ex28 {
+    #[mapval(code = 404u16, ch = 'a')]
+    V1,
+    #[mapval(code = 404)]
+    V2,
+    #[mapval(code = 0x10, ch = '\x61')]
+    V3,
+    #[mapval(code = 16)]
+    V4,
+}
+
+#[derive(EnumMap)]
+enum Ex29 {
+    #[mapstr("v_{0}", name = "n", sql_ddl)]
+    V1(u8),
+}
+
+#[derive(EnumMap)]
+enum Ex30 {
+    #[mapstr("a", name = "n", trim, parse_prefix)]
+    V1,
+}
+
+#[derive(EnumMap)]
+enum Ex31 {
+    #[mapstr("a", name = "n", ignore_case, const_from)]
+    V1,
+}
+
+fn ex24() {
+    ex24_n!("b");
+    ex24_n!("v2");
+}
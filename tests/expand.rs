@@ -1,5 +1,6 @@
 use enum_map::EnumMap;
 
+#[allow(dead_code)]
 #[derive(EnumMap)]
 enum E {
     #[mapstr("a", name = "n", display)]
@@ -0,0 +1,102 @@
+use enum_map::EnumMap;
+use rusqlite::types::{FromSql, FromSqlError, ValueRef};
+use rusqlite::Connection;
+
+#[test]
+fn rusqlite() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum Status {
+        #[mapstr("active", name = "db", sql)]
+        Active,
+        #[mapstr("archived", aliases("deleted"))]
+        Archived,
+        #[mapstr("retry_{0}")]
+        Retry(u8),
+        Draft,
+    }
+
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute("CREATE TABLE items (id INTEGER PRIMARY KEY, status TEXT NOT NULL)", ())
+        .unwrap();
+    for status in [Status::Active, Status::Archived, Status::Retry(3)] {
+        conn.execute("INSERT INTO items (status) VALUES (?1)", [&status]).unwrap();
+    }
+    conn.execute("INSERT INTO items (status) VALUES ('deleted'), ('unknown')", ())
+        .unwrap();
+
+    let stored: Vec<String> = conn
+        .prepare("SELECT status FROM items ORDER BY id")
+        .unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(stored, ["active", "archived", "retry_3", "deleted", "unknown"]);
+
+    let get = |id: i64| conn.query_row("SELECT status FROM items WHERE id = ?1", [id], |row| row.get::<_, Status>(0));
+    assert_eq!(get(1).unwrap(), Status::Active);
+    assert_eq!(get(2).unwrap(), Status::Archived);
+    assert_eq!(get(3).unwrap(), Status::Retry(3));
+    assert_eq!(get(4).unwrap(), Status::Archived);
+    let err = get(5).unwrap_err();
+    assert!(matches!(err, rusqlite::Error::FromSqlConversionFailure(..)), "{err:?}");
+    assert!(err.to_string().contains("unknown"), "{err}");
+
+    match Status::column_result(ValueRef::Text(b"unknown")) {
+        Err(FromSqlError::Other(e)) => {
+            let e = e.downcast_ref::<ParseStatusError>().unwrap();
+            assert_eq!(e.input, "unknown");
+            assert_eq!(e.mapping, "db");
+        }
+        other => panic!("unexpected result {other:?}"),
+    }
+    assert!(matches!(
+        Status::column_result(ValueRef::Integer(1)),
+        Err(FromSqlError::InvalidType)
+    ));
+
+    let err = conn
+        .execute("INSERT INTO items (status) VALUES (?1)", [&Status::Draft])
+        .unwrap_err();
+    assert!(matches!(err, rusqlite::Error::ToSqlConversionFailure(_)), "{err:?}");
+    assert_eq!(Status::Draft.try_to_db(), None);
+
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    #[enum_map(kind(sql, default = Other))]
+    enum Kind {
+        #[mapstr(kind = "a")]
+        A,
+        #[mapstr(kind = "other")]
+        Other,
+    }
+
+    assert_eq!(Kind::column_result(ValueRef::Text(b"b")), Ok(Kind::Other));
+    let a: Kind = conn.query_row("SELECT ?1", [&Kind::A], |row| row.get(0)).unwrap();
+    assert_eq!(a, Kind::A);
+}
+
+#[test]
+fn sql_check() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum Quote {
+        #[mapstr("it's", name = "quote", sql, sql_ddl)]
+        Single,
+        #[mapstr("\"double\"")]
+        Double,
+    }
+
+    let conn = Connection::open_in_memory().unwrap();
+    let table = format!("CREATE TABLE quotes (text TEXT NOT NULL {})", Quote::quote_sql_check("text"));
+    conn.execute(&table, ()).unwrap();
+    conn.execute("INSERT INTO quotes (text) VALUES (?1), (?2)", [&Quote::Single, &Quote::Double])
+        .unwrap();
+    assert!(conn.execute("INSERT INTO quotes (text) VALUES ('its')", ()).is_err());
+
+    let count: i64 = conn.query_row("SELECT COUNT(*) FROM quotes", [], |row| row.get(0)).unwrap();
+    assert_eq!(count, 2);
+
+    let table = format!("CREATE TABLE named (quote TEXT NOT NULL {})", Quote::QUOTE_SQL_CHECK);
+    conn.execute(&table, ()).unwrap();
+    conn.execute("INSERT INTO named (quote) VALUES (?1)", [&Quote::Double]).unwrap();
+    assert!(conn.execute("INSERT INTO named (quote) VALUES ('double')", ()).is_err());
+}
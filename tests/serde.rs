@@ -0,0 +1,121 @@
+use enum_map::EnumMap;
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn serde() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum Format {
+        #[mapstr("json", name = "short", serde)]
+        #[mapstr("JSON document", name = "pretty")]
+        Json,
+        #[mapstr("csv", aliases("tsv"))]
+        #[mapstr("Comma separated values")]
+        Csv,
+        #[mapstr("v{0}")]
+        #[mapstr("Version {0}")]
+        Version(u8),
+        Other,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+    struct Config {
+        format: Format,
+    }
+
+    let config = Config { format: Format::Json };
+    let s = serde_json::to_string(&config).unwrap();
+    assert_eq!(s, r#"{"format":"json"}"#);
+    assert_eq!(serde_json::from_str::<Config>(&s).unwrap(), config);
+
+    assert_eq!(serde_json::to_string(&Format::Version(2)).unwrap(), r#""v2""#);
+    assert_eq!(serde_json::from_str::<Format>(r#""v2""#).unwrap(), Format::Version(2));
+    assert_eq!(serde_json::from_str::<Format>(r#""tsv""#).unwrap(), Format::Csv);
+    assert_eq!(Format::Other.try_to_pretty(), None);
+    assert_eq!(Format::Version(1).try_to_pretty(), Some("Version 1".into()));
+
+    let err = serde_json::to_string(&Format::Other).unwrap_err();
+    assert_eq!(err.to_string(), "variant is not mapped in maping `short`");
+    let err = serde_json::from_str::<Format>(r#""xml""#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unknown variant `xml`, expected one of `json`, `csv`, `tsv`, `v{0}`"
+    );
+    assert!(serde_json::from_str::<Format>("1").is_err());
+
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    #[enum_map(code(serde, default = Unknown))]
+    enum Status {
+        #[mapstr(code = "ok")]
+        Ok,
+        #[mapstr(code = "failed")]
+        Failed,
+        #[mapstr(code = "unknown")]
+        Unknown,
+    }
+
+    let statuses: Vec<Status> = serde_json::from_str(r#"["ok", "failed", "retrying"]"#).unwrap();
+    assert_eq!(statuses, vec![Status::Ok, Status::Failed, Status::Unknown]);
+    assert_eq!(serde_json::to_string(&statuses).unwrap(), r#"["ok","failed","unknown"]"#);
+}
+
+#[derive(EnumMap, Debug, Eq, PartialEq)]
+#[enum_map(short(serde_with), vname(serde_with, default = Unknown))]
+enum Level {
+    #[mapstr(short = "d", vname = "debug")]
+    Debug,
+    #[mapstr(short = "w", vname = "warning")]
+    Warning,
+    #[mapstr(vname = "unknown")]
+    Unknown,
+}
+
+#[derive(EnumMap, Debug, Eq, PartialEq)]
+enum Wrapped<T: std::fmt::Display + std::str::FromStr> {
+    #[mapstr("value_{0}", name = "n", serde_with)]
+    Value(T),
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct Compact {
+    #[serde(with = "level_short_serde")]
+    level: Level,
+    #[serde(with = "level_short_serde::option")]
+    min: Option<Level>,
+    #[serde(with = "level_short_serde::vec")]
+    all: Vec<Level>,
+    #[serde(with = "wrapped_n_serde")]
+    wrapped: Wrapped<u8>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct Pretty {
+    #[serde(with = "level_vname_serde")]
+    level: Level,
+    #[serde(with = "level_vname_serde::option")]
+    min: Option<Level>,
+}
+
+#[test]
+fn serde_with() {
+    let compact = Compact {
+        level: Level::Debug,
+        min: Some(Level::Warning),
+        all: vec![Level::Debug, Level::Warning],
+        wrapped: Wrapped::Value(5),
+    };
+    let s = serde_json::to_string(&compact).unwrap();
+    assert_eq!(s, r#"{"level":"d","min":"w","all":["d","w"],"wrapped":"value_5"}"#);
+    assert_eq!(serde_json::from_str::<Compact>(&s).unwrap(), compact);
+
+    let err = serde_json::from_str::<Compact>(r#"{"level":"x","min":null,"all":[],"wrapped":"value_1"}"#).unwrap_err();
+    assert_eq!(err.to_string(), "unknown variant `x`, expected `d` or `w` at line 1 column 12");
+    let err = serde_json::to_string(&Compact { level: Level::Unknown, ..compact }).unwrap_err();
+    assert_eq!(err.to_string(), "variant is not mapped in maping `short`");
+
+    let pretty = Pretty { level: Level::Warning, min: None };
+    let s = serde_json::to_string(&pretty).unwrap();
+    assert_eq!(s, r#"{"level":"warning","min":null}"#);
+    assert_eq!(serde_json::from_str::<Pretty>(&s).unwrap(), pretty);
+    let pretty: Pretty = serde_json::from_str(r#"{"level":"error","min":"info"}"#).unwrap();
+    assert_eq!(pretty, Pretty { level: Level::Unknown, min: Some(Level::Unknown) });
+}
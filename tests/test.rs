@@ -259,3 +259,956 @@ fn display_no_default() {
     assert_eq!(format!("{}", E::Unknown), String::from("unknown"));
     assert_eq!(format!("{}", E::Err), String::from("Unknown variant"));
 }
+
+#[test]
+fn generics() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum Token<'a, T: Clone, const N: usize>
+    where
+        T: Default,
+    {
+        #[mapstr("ident", name = "kind", display)]
+        Ident(&'a str),
+        #[mapstr("num")]
+        Num(T),
+        #[mapstr("arr")]
+        Arr([u8; N]),
+        #[mapstr("eof", default)]
+        Eof,
+    }
+
+    type T<'a> = Token<'a, u32, 2>;
+
+    assert_eq!(T::Ident("x").to_kind(), "ident");
+    assert_eq!(T::Num(1).to_kind(), "num");
+    assert_eq!(T::Arr([1, 2]).to_kind(), "arr");
+    assert_eq!(T::Eof.to_kind(), "eof");
+
+    assert_eq!(T::from_kind("eof"), T::Eof);
+    assert_eq!(T::from_kind("ident"), T::Eof);
+    assert_eq!(T::from_kind("random"), T::Eof);
+
+    assert_eq!(format!("{}", T::Ident("x")), String::from("ident"));
+    assert_eq!(format!("{}", T::Arr([1, 2])), String::from("arr"));
+    assert_eq!(format!("{}", T::Eof), String::from("eof"));
+}
+
+#[test]
+fn generics_lifetimes() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E<'a, 'b: 'a> {
+        #[mapstr("a", name = "vname")]
+        A(&'a str),
+        #[mapstr("b")]
+        B(&'b str),
+        #[mapstr("c")]
+        C,
+    }
+
+    assert_eq!(E::A("x").to_vname(), "a");
+    assert_eq!(E::B("y").to_vname(), "b");
+    assert_eq!(E::C.to_vname(), "c");
+    assert_eq!(E::try_from_vname("c"), Some(E::C));
+    assert_eq!(E::try_from_vname("a"), None);
+}
+
+#[test]
+fn templates() {
+    use std::borrow::Cow;
+
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E {
+        #[mapstr("port_{0}", name = "vname", display)]
+        #[mapstr("P", name = "short")]
+        Port(u16),
+
+        #[mapstr("{host}:{port}")]
+        #[mapstr("A")]
+        Addr { host: String, port: u16 },
+
+        #[mapstr("{1}-{{{0}}}")]
+        #[mapstr("R")]
+        Range(u8, u8, u8),
+
+        #[mapstr("named")]
+        #[mapstr("N")]
+        Named { x: i32 },
+
+        #[mapstr("none", default)]
+        None,
+    }
+
+    assert_eq!(E::Port(80).to_vname(), "port_80");
+    assert!(matches!(E::Port(80).to_vname(), Cow::Owned(_)));
+    assert_eq!(
+        E::Addr {
+            host: String::from("localhost"),
+            port: 8080
+        }
+        .to_vname(),
+        "localhost:8080"
+    );
+    assert_eq!(E::Range(1, 2, 3).to_vname(), "2-{1}");
+    assert_eq!(E::Named { x: 1 }.to_vname(), "named");
+    assert!(matches!(E::Named { x: 1 }.to_vname(), Cow::Borrowed("named")));
+    assert_eq!(E::None.to_vname(), "none");
+
+    // Mapings without field references still return `&'static str`
+    let s: Option<&'static str> = E::Port(80).try_to_short();
+    assert_eq!(s, Some("P"));
+    assert_eq!(E::Named { x: 1 }.try_to_short(), Some("N"));
+
+    assert_eq!(format!("{}", E::Port(80)), String::from("port_80"));
+    assert_eq!(
+        format!(
+            "{}",
+            E::Addr {
+                host: String::from("localhost"),
+                port: 8080
+            }
+        ),
+        String::from("localhost:8080")
+    );
+    assert_eq!(format!("{}", E::Range(1, 2, 3)), String::from("2-{1}"));
+    assert_eq!(format!("{}", E::None), String::from("none"));
+}
+
+#[test]
+fn templates_from() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E {
+        #[mapstr("port_{0}", name = "vname")]
+        Port(u16),
+
+        #[mapstr("{host}:{port}")]
+        Addr { host: String, port: u16 },
+
+        #[mapstr("[{0}..{1}]")]
+        Range(i32, i32),
+
+        #[mapstr("name_{0}")]
+        Name(String),
+
+        // Exact matches take precedence over templates
+        #[mapstr("port_any")]
+        AnyPort,
+
+        // Not all fields are referenced, cannot be created
+        #[mapstr("pair_{0}")]
+        Pair(u8, u8),
+
+        #[mapstr("none", default)]
+        None,
+    }
+
+    assert_eq!(E::from_vname("port_80"), E::Port(80));
+    assert_eq!(E::from_vname("port_any"), E::AnyPort);
+    assert_eq!(E::from_vname("port_"), E::None);
+    assert_eq!(E::from_vname("port_-1"), E::None);
+    assert_eq!(
+        E::from_vname("localhost:8080"),
+        E::Addr {
+            host: String::from("localhost"),
+            port: 8080
+        }
+    );
+    assert_eq!(E::from_vname("[-5..10]"), E::Range(-5, 10));
+    assert_eq!(E::from_vname("[-5..10"), E::None);
+    assert_eq!(E::from_vname("name_x_y"), E::Name(String::from("x_y")));
+    assert_eq!(E::Pair(1, 2).to_vname(), "pair_1");
+    assert_eq!(E::from_vname("pair_1"), E::None);
+    assert_eq!(E::from_vname("none"), E::None);
+
+    for v in [E::Port(1), E::Range(1, 2), E::Name(String::from("a:b"))] {
+        assert_eq!(E::from_vname(&v.to_vname()), v);
+    }
+}
+
+#[test]
+fn templates_from_order() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E {
+        #[mapstr("v{0}", name = "vname")]
+        Num(u8),
+        #[mapstr("v{0}!")]
+        Text(String),
+    }
+
+    // Templates are tried in declaration order, next one is tried if field fails to parse
+    assert_eq!(E::try_from_vname("v1"), Some(E::Num(1)));
+    assert_eq!(E::try_from_vname("v1!"), Some(E::Text(String::from("1"))));
+    assert_eq!(E::try_from_vname("vx"), None);
+}
+
+#[test]
+fn field_values() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E {
+        #[mapstr("retry", name = "vname", default_from = Other(String::new()))]
+        Retry { attempts: u8 },
+
+        #[mapstr("retry_many", fields(attempts = 3 + 7, delay = 100))]
+        RetryN { attempts: u8, delay: u32 },
+
+        #[mapstr("wait", fields = default)]
+        Wait(u32, Option<String>),
+
+        #[mapstr("pair_{1}", fields(0 = 1))]
+        Pair(u8, u8),
+
+        #[mapstr("other_{0}")]
+        Other(String),
+    }
+
+    // Retry has no values for fields, it cannot be created
+    assert_eq!(E::Retry { attempts: 1 }.to_vname(), "retry");
+    assert_eq!(E::from_vname("retry"), E::Other(String::new()));
+    assert_eq!(
+        E::from_vname("retry_many"),
+        E::RetryN {
+            attempts: 10,
+            delay: 100
+        }
+    );
+    assert_eq!(E::from_vname("wait"), E::Wait(0, None));
+    assert_eq!(E::from_vname("pair_2"), E::Pair(1, 2));
+    assert_eq!(E::from_vname("other_x"), E::Other(String::from("x")));
+    assert_eq!(E::from_vname("random"), E::Other(String::new()));
+}
+
+#[test]
+fn field_values_default() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E {
+        #[mapstr("retry", name = "vname", fields(attempts = 3))]
+        #[mapstr("retry", name = "mixed", fields(attempts = 3))]
+        Retry { attempts: u8 },
+
+        #[mapstr("other", name = "vname", default, fields = default)]
+        #[mapstr("other_{delay}", name = "mixed", fields = default)]
+        Other { message: String, delay: u32 },
+    }
+
+    assert_eq!(E::from_vname("retry"), E::Retry { attempts: 3 });
+    assert_eq!(
+        E::from_vname("random"),
+        E::Other {
+            message: String::new(),
+            delay: 0
+        }
+    );
+    assert_eq!(E::try_from_mixed("retry"), Some(E::Retry { attempts: 3 }));
+    assert_eq!(
+        E::try_from_mixed("other_5"),
+        Some(E::Other {
+            message: String::new(),
+            delay: 5
+        })
+    );
+    assert_eq!(E::try_from_mixed("other"), None);
+}
+
+#[test]
+fn from_str() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum Example {
+        #[mapstr("variant_1", name = "vname", from_str)]
+        #[mapstr("V1", name = "short", default_from = Unknown)]
+        V1,
+
+        #[mapstr("port_{0}")]
+        #[mapstr("V2")]
+        V2(u16),
+
+        Unknown,
+    }
+
+    assert_eq!("variant_1".parse::<Example>(), Ok(Example::V1));
+    assert_eq!("port_80".parse::<Example>(), Ok(Example::V2(80)));
+    assert_eq!(Example::from_short("V1"), Example::V1);
+    assert_eq!(Example::from_short("V2"), Example::Unknown);
+
+    let err = "V1".parse::<Example>().unwrap_err();
+    assert_eq!(
+        err,
+        ParseExampleError {
+            input: String::from("V1"),
+            mapping: "vname",
+            expected: &["variant_1", "port_{0}"],
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid vname value `V1`, expected one of: `variant_1`, `port_{0}`"
+    );
+    let _: &dyn std::error::Error = &err;
+
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E2 {
+        #[mapstr("a", name = "vname")]
+        #[mapstr("A", name = "short", default_from = Unknown, from_str)]
+        A,
+        Unknown,
+    }
+
+    // Defaults are used, parsing never fails
+    assert_eq!("A".parse::<E2>(), Ok(E2::A));
+    assert_eq!("a".parse::<E2>(), Ok(E2::Unknown));
+    assert_eq!(E2::try_from_vname("a"), Some(E2::A));
+}
+
+#[test]
+fn conversions() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E {
+        #[mapstr("variant_1", name = "vname", try_from_str, into_str)]
+        V1,
+
+        #[mapstr("variant_2")]
+        V2(u8),
+
+        Unknown,
+    }
+
+    // No defaults, `TryFrom` is implemented
+    assert_eq!(E::try_from("variant_1"), Ok(E::V1));
+    assert_eq!(
+        E::try_from("V1"),
+        Err(ParseEError {
+            input: String::from("V1"),
+            mapping: "vname",
+            expected: &["variant_1"],
+        })
+    );
+    assert_eq!(<&'static str>::try_from(E::V1), Ok("variant_1"));
+    assert_eq!(<&'static str>::try_from(E::V2(1)), Ok("variant_2"));
+    assert_eq!(<&'static str>::try_from(E::Unknown), Err(E::Unknown));
+
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E2<T> {
+        #[mapstr("V1", name = "short", default, try_from_str, into_str, as_ref)]
+        V1,
+        #[mapstr("V2")]
+        V2(T),
+        Unknown,
+    }
+
+    // Defaults are set, `From` is implemented
+    assert_eq!(E2::<u8>::from("V1"), E2::V1);
+    assert_eq!(E2::<u8>::from("random"), E2::V1);
+    let s: &'static str = E2::V2(1).into();
+    assert_eq!(s, "V2");
+    let s: &'static str = E2::<u8>::Unknown.into();
+    assert_eq!(s, "V1");
+    assert_eq!(E2::V2(1).as_ref(), "V2");
+    assert_eq!(E2::<u8>::Unknown.as_ref(), "V1");
+}
+
+#[test]
+fn normalization() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E {
+        #[mapstr("Kilo-Byte", name = "unit", ignore_ascii_case, trim, ignore_chars = "-_")]
+        Kb,
+
+        #[mapstr("Mega-Byte")]
+        Mb,
+
+        #[mapstr("Block {0}")]
+        Block(u8),
+    }
+
+    assert_eq!(E::try_from_unit("Kilo-Byte"), Some(E::Kb));
+    assert_eq!(E::try_from_unit("  kilobyte "), Some(E::Kb));
+    assert_eq!(E::try_from_unit("MEGA_BYTE"), Some(E::Mb));
+    assert_eq!(E::try_from_unit("block 12 "), Some(E::Block(12)));
+    assert_eq!(E::try_from_unit("giga-byte"), None);
+    assert_eq!(E::Kb.to_unit(), "Kilo-Byte");
+
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E2 {
+        #[mapstr("Straße", name = "street", ignore_case, default_from = Unknown, from_str)]
+        Street,
+        Unknown,
+    }
+
+    assert_eq!(E2::from_street("STRASSE"), E2::Unknown);
+    assert_eq!(E2::from_street("STRAẞE"), E2::Street);
+    assert_eq!("straße".parse::<E2>(), Ok(E2::Street));
+
+    // Only literal parts of templates are normalized, field values are kept as written
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E3 {
+        #[mapstr("user_{0}", name = "n", ignore_ascii_case, ignore_chars = "-")]
+        Named(String),
+
+        #[mapstr("tag-{0}")]
+        Tag(String),
+
+        #[mapstr("{0}:{1}-End")]
+        Pair(String, String),
+    }
+
+    assert_eq!(E3::try_from_n("User_BobSmith"), Some(E3::Named("BobSmith".into())));
+    assert_eq!(E3::try_from_n("-user-_Bob-Smith"), Some(E3::Named("Bob-Smith".into())));
+    assert_eq!(E3::try_from_n("TAG-a-b-c"), Some(E3::Tag("a-b-c".into())));
+    assert_eq!(E3::try_from_n("Ab:Cd-e-N-D"), Some(E3::Pair("Ab".into(), "Cd".into())));
+    assert_eq!(E3::try_from_n("us_x"), None);
+}
+
+#[test]
+fn aliases() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E {
+        #[mapstr("kilobyte", name = "unit", aliases("kb", "KiB"), display)]
+        Kb,
+
+        #[mapstr("megabyte")]
+        Mb,
+
+        #[mapstr("block_{0}", aliases("b{0}"))]
+        Block(u8),
+    }
+
+    assert_eq!(E::try_from_unit("kilobyte"), Some(E::Kb));
+    assert_eq!(E::try_from_unit("kb"), Some(E::Kb));
+    assert_eq!(E::try_from_unit("KiB"), Some(E::Kb));
+    assert_eq!(E::try_from_unit("b12"), Some(E::Block(12)));
+    assert_eq!(E::try_from_unit("block_12"), Some(E::Block(12)));
+    assert_eq!(E::try_from_unit("mb"), None);
+
+    // Only canonical values are returned
+    assert_eq!(E::Kb.to_unit(), "kilobyte");
+    assert_eq!(E::Block(1).to_string(), "block_1");
+
+    assert_eq!(E::Kb.unit_aliases(), &["kb", "KiB"]);
+    assert_eq!(E::Block(1).unit_aliases(), &["b{0}"]);
+    assert!(E::Mb.unit_aliases().is_empty());
+}
+
+#[test]
+fn duplicates() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E {
+        #[mapstr("error", name = "level", allow_duplicate)]
+        Error,
+
+        #[mapstr("error", primary)]
+        Fatal,
+
+        #[mapstr("warning")]
+        Warning,
+    }
+
+    assert_eq!(E::Error.to_level(), "error");
+    assert_eq!(E::Fatal.to_level(), "error");
+    assert_eq!(E::try_from_level("error"), Some(E::Fatal));
+    assert_eq!(E::try_from_level("warning"), Some(E::Warning));
+
+    // Without `from` functions primary is not needed
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E2 {
+        #[mapstr("a", name = "short", allow_duplicate, no_from)]
+        A1,
+        #[mapstr("a")]
+        A2,
+    }
+
+    assert_eq!(E2::A1.to_short(), "a");
+    assert_eq!(E2::A2.to_short(), "a");
+}
+
+#[test]
+fn exhaustive() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum E {
+        #[mapstr("a", name = "vname", display, into_str, as_ref)]
+        #[mapstr("A", name = "short", exhaustive, r#try)]
+        A,
+        #[mapstr("b")]
+        #[mapstr("B")]
+        B,
+        #[mapstr("c")]
+        #[mapstr("C")]
+        C,
+    }
+
+    // Every variant is mapped, no defaults are needed
+    assert_eq!(E::A.to_vname(), "a");
+    assert_eq!(E::C.to_vname(), "c");
+    assert_eq!(E::C.to_string(), "c");
+    assert_eq!(E::B.as_ref(), "b");
+    let s: &'static str = E::B.into();
+    assert_eq!(s, "b");
+    // `try_to` is still created without `try`
+    assert_eq!(E::B.try_to_vname(), Some("b"));
+
+    assert_eq!(E::A.to_short(), "A");
+    assert_eq!(E::A.try_to_short(), Some("A"));
+    assert_eq!(E::C.try_to_short(), Some("C"));
+    assert_eq!(E::try_from_short("B"), Some(E::B));
+}
+
+#[test]
+fn rename_all() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    #[enum_map(
+        mapping(name = "snake", rename_all = "snake_case"),
+        mapping(name = "kebab", rename_all = "kebab-case"),
+        mapping(name = "screaming", rename_all = "SCREAMING_SNAKE_CASE"),
+        mapping(name = "camel", rename_all = "camelCase"),
+        mapping(name = "pascal", rename_all = "PascalCase"),
+        mapping(name = "title", rename_all = "Title Case"),
+        mapping(name = "lower", rename_all = "lowercase")
+    )]
+    enum E {
+        HttpServer,
+        HTTPError2,
+        // Explicit values override generated ones, unnamed `mapstr` refers to the first declared maping
+        #[mapstr("variant_3")]
+        #[mapstr("v-3", name = "kebab")]
+        V3,
+        Block(u8),
+    }
+
+    assert_eq!(E::HttpServer.to_snake(), "http_server");
+    assert_eq!(E::HttpServer.to_kebab(), "http-server");
+    assert_eq!(E::HttpServer.to_screaming(), "HTTP_SERVER");
+    assert_eq!(E::HttpServer.to_camel(), "httpServer");
+    assert_eq!(E::HttpServer.to_pascal(), "HttpServer");
+    assert_eq!(E::HttpServer.to_title(), "Http Server");
+    assert_eq!(E::HttpServer.to_lower(), "httpserver");
+
+    assert_eq!(E::HTTPError2.to_snake(), "http_error2");
+    assert_eq!(E::HTTPError2.to_camel(), "httpError2");
+    assert_eq!(E::V3.to_snake(), "variant_3");
+    assert_eq!(E::V3.to_kebab(), "v-3");
+    assert_eq!(E::V3.to_title(), "V3");
+    assert_eq!(E::Block(1).to_snake(), "block");
+
+    assert_eq!(E::try_from_snake("http_error2"), Some(E::HTTPError2));
+    assert_eq!(E::try_from_kebab("v-3"), Some(E::V3));
+    assert_eq!(E::try_from_kebab("v3"), None);
+    // Variant with fields cannot be constructed
+    assert_eq!(E::try_from_snake("block"), None);
+}
+
+#[test]
+fn declared_mapings() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    #[enum_map(vname(default = Unknown, display), short(r#try, ignore_case))]
+    enum E {
+        #[mapstr(vname = "variant_1", short = "V1")]
+        V1,
+        // Order of attributes doesn't matter
+        #[mapstr("v2", name = "short")]
+        #[mapstr("variant_2", name = "vname")]
+        V2,
+        #[mapstr(vname = "unknown")]
+        Unknown,
+    }
+
+    assert_eq!(E::V1.to_vname(), "variant_1");
+    assert_eq!(E::V2.to_vname(), "variant_2");
+    assert_eq!(E::from_vname("variant_2"), E::V2);
+    assert_eq!(E::from_vname("random"), E::Unknown);
+    assert_eq!(E::V1.to_string(), "variant_1");
+
+    assert_eq!(E::V1.try_to_short(), Some("V1"));
+    assert_eq!(E::Unknown.try_to_short(), None);
+    assert_eq!(E::try_from_short("v1"), Some(E::V1));
+    assert_eq!(E::try_from_short("unknown"), None);
+
+    // Default variant with fields and `mapping(..)` form with options
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    #[enum_map(mapping(name = "vname", rename_all = "snake_case", default = Other(String::new()), from_str))]
+    enum E2 {
+        FirstVariant,
+        Other(String),
+    }
+
+    assert_eq!(E2::FirstVariant.to_vname(), "first_variant");
+    assert_eq!(E2::Other(String::from("x")).to_vname(), "other");
+    assert_eq!("first_variant".parse::<E2>(), Ok(E2::FirstVariant));
+    assert_eq!("x".parse::<E2>(), Ok(E2::Other(String::new())));
+}
+
+#[test]
+fn value_mapings() {
+    const TEAPOT: u16 = 418;
+
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    #[enum_map(code(ty = u16, default = Unknown), tag(ty = &'static [u8], exhaustive))]
+    enum E {
+        #[mapval(code = 404, flag = 'n', tag = b"NF")]
+        NotFound,
+        #[mapval(code = TEAPOT, tag = b"TEA")]
+        Teapot,
+        #[mapval(code = 0, tag = b"")]
+        Unknown,
+        #[mapval(flag = 'o', tag = b"OTHER")]
+        Other(u8),
+    }
+
+    assert_eq!(E::NotFound.to_code(), 404);
+    assert_eq!(E::Teapot.to_code(), 418);
+    assert_eq!(E::Other(1).to_code(), 0);
+    assert_eq!(E::from_code(404), E::NotFound);
+    assert_eq!(E::from_code(418), E::Teapot);
+    assert_eq!(E::from_code(1), E::Unknown);
+
+    // Type is inferred from literal
+    assert_eq!(E::NotFound.try_to_flag(), Some('n'));
+    assert_eq!(E::Teapot.try_to_flag(), None);
+    assert_eq!(E::try_from_flag('n'), Some(E::NotFound));
+    // Variant with fields cannot be constructed
+    assert_eq!(E::try_from_flag('o'), None);
+
+    assert_eq!(E::Teapot.to_tag(), b"TEA");
+    assert_eq!(E::Other(1).to_tag(), b"OTHER");
+    assert_eq!(E::Other(1).try_to_tag(), Some(&b"OTHER"[..]));
+    assert_eq!(E::try_from_tag(b"NF"), Some(E::NotFound));
+    assert_eq!(E::try_from_tag(b"X"), None);
+}
+
+#[test]
+fn value_ranges() {
+    const MIN_CLIENT_ERROR: i32 = 400;
+
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    #[enum_map(code(ty = i32, default = Unknown))]
+    enum E {
+        #[mapval(code = 200, from_range = 200..300)]
+        Success,
+        #[mapval(code = 404)]
+        NotFound,
+        #[mapval(code = 400, from_range = [MIN_CLIENT_ERROR..404, 405..=499])]
+        ClientError,
+        #[mapval(code = 500, from_range = 500..)]
+        ServerError,
+        #[mapval(code = -1, from_range = ..0)]
+        Negative,
+        #[mapval(code = 0)]
+        Unknown,
+    }
+
+    assert_eq!(E::Success.to_code(), 200);
+    assert_eq!(E::ServerError.to_code(), 500);
+    assert_eq!(E::from_code(200), E::Success);
+    assert_eq!(E::from_code(299), E::Success);
+    assert_eq!(E::from_code(300), E::Unknown);
+    assert_eq!(E::from_code(404), E::NotFound);
+    assert_eq!(E::from_code(400), E::ClientError);
+    assert_eq!(E::from_code(403), E::ClientError);
+    assert_eq!(E::from_code(405), E::ClientError);
+    assert_eq!(E::from_code(1000), E::ServerError);
+    assert_eq!(E::from_code(-5), E::Negative);
+    assert_eq!(E::from_code(0), E::Unknown);
+}
+
+#[test]
+fn meta() {
+    const RETRY_DELAY: u32 = 100;
+
+    #[derive(EnumMap, Debug, PartialEq)]
+    #[mapmeta(code: u16)]
+    enum E {
+        #[mapmeta(code = 7, label = "Timeout", retryable = true, delay = RETRY_DELAY)]
+        Timeout,
+        #[mapmeta(code = 8, label = "Busy")]
+        #[mapmeta(retryable = true, delay = 10u32)]
+        Busy { attempts: u8 },
+        #[mapmeta(code = 9, label = "Invalid", retryable = false, delay = 0)]
+        Invalid(String),
+    }
+
+    assert_eq!(
+        E::Timeout.meta(),
+        &EMeta {
+            code: 7,
+            label: "Timeout",
+            retryable: true,
+            delay: 100
+        }
+    );
+    assert_eq!(E::Busy { attempts: 1 }.meta().code, 8);
+    assert_eq!(E::Busy { attempts: 1 }.meta().delay, 10);
+    assert_eq!(E::Invalid(String::new()).meta().label, "Invalid");
+    assert!(!E::Invalid(String::new()).meta().retryable);
+    assert_ne!(E::Busy { attempts: 1 }, E::Invalid(String::new()));
+}
+
+#[test]
+fn tables() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    #[enum_map(tables)]
+    enum E {
+        #[mapstr("a", name = "n", aliases("x"), tables)]
+        A,
+        #[mapstr("b_{0}")]
+        B(u8),
+        #[mapstr("c", fields(x = 1))]
+        C { x: u8 },
+        #[mapstr("a", allow_duplicate, primary)]
+        D,
+    }
+
+    assert_eq!(E::N_STRS, &["a", "c"]);
+    assert_eq!(
+        E::n_pairs().collect::<Vec<_>>(),
+        vec![(E::A, "a"), (E::C { x: 1 }, "c"), (E::D, "a")]
+    );
+    assert_eq!(E::VARIANT_COUNT, 4);
+    // Templated values are not listed
+    assert_eq!(E::B(1).to_n(), "b_1");
+
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    #[enum_map(tables)]
+    enum Unit {
+        #[mapstr("x", name = "n", no_from, tables)]
+        X,
+        #[mapstr("y")]
+        Y,
+    }
+
+    assert_eq!(Unit::ALL, &[Unit::X, Unit::Y]);
+    assert_eq!(Unit::VARIANT_COUNT, Unit::ALL.len());
+    assert_eq!(Unit::n_pairs().count(), 2);
+    assert!(Unit::ALL.iter().all(|v| Unit::N_STRS.contains(&v.to_n())));
+
+    // Items are only created if requested, so they don't clash with ones defined on the enum
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum Own {
+        #[mapstr("x", name = "n")]
+        X,
+    }
+
+    impl Own {
+        const ALL: &'static [Self] = &[Self::X];
+        const N_STRS: &'static [&'static str] = &["own"];
+    }
+
+    assert_eq!(Own::ALL, &[Own::X]);
+    assert_eq!(Own::N_STRS, &["own"]);
+}
+
+#[test]
+fn const_fns() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    #[enum_map(code(ty = u8))]
+    enum E {
+        #[mapstr("Alpha", name = "n", ignore_ascii_case, aliases("a"), const_from)]
+        #[mapval(code = 1)]
+        A,
+        #[mapstr("beta")]
+        #[mapstr("b", name = "short")]
+        #[mapval(code = 2)]
+        B,
+        #[mapstr("c_{0}")]
+        C(u8),
+    }
+
+    const A: Option<E> = E::try_from_n_const("ALPHA");
+    const ALIAS: Option<E> = E::try_from_n_const("A");
+    // `n` has a template so `try_to_n` is not const, `short` has only literals
+    const NAME: Option<&str> = E::B.try_to_short();
+    const CODE: Option<u8> = E::B.try_to_code();
+    const LEN: usize = match CODE {
+        Some(c) => c as usize + 1,
+        None => 0,
+    };
+
+    assert_eq!(A, Some(E::A));
+    assert_eq!(ALIAS, Some(E::A));
+    assert_eq!(NAME, Some("b"));
+    assert_eq!([0u8; LEN].len(), 3);
+    assert_eq!(E::try_from_n_const("b"), None);
+    // Templates are not parsed in const fn
+    assert_eq!(E::try_from_n_const("c_1"), None);
+    assert_eq!(E::try_from_n("c_1"), Some(E::C(1)));
+    assert_eq!(E::try_from_code(1), Some(E::A));
+
+    // Not created without `const_from`, so own function with the same name is allowed
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum Own {
+        #[mapstr("a", name = "n")]
+        A,
+    }
+
+    impl Own {
+        const fn try_from_n_const(_: &str) -> Option<Self> {
+            Some(Self::A)
+        }
+    }
+
+    assert_eq!(Own::try_from_n_const("b"), Some(Own::A));
+}
+
+#[test]
+fn lit_macro() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    #[enum_map(vname(lit_macro))]
+    enum HttpMethod {
+        #[mapstr(vname = "get")]
+        Get,
+        #[mapstr("post", aliases("POST"))]
+        Post,
+        #[mapstr("retry", fields(attempts = 3))]
+        Retry { attempts: u8 },
+        #[mapstr(vname = "other_{0}")]
+        Other(String),
+    }
+
+    assert_eq!(http_method_vname!("get"), HttpMethod::Get);
+    assert_eq!(http_method_vname!("POST"), HttpMethod::Post);
+    assert_eq!(http_method_vname!("retry"), HttpMethod::Retry { attempts: 3 });
+    assert_eq!(HttpMethod::try_from_vname("other_a"), Some(HttpMethod::Other(String::from("a"))));
+    const GET: HttpMethod = http_method_vname!("get");
+    assert_eq!(GET.to_vname(), "get");
+
+    mod inner {
+        use enum_map::EnumMap;
+
+        #[derive(EnumMap, Debug, Eq, PartialEq)]
+        pub enum Level {
+            #[mapstr("debug", name = "n", lit_macro)]
+            Debug,
+        }
+    }
+
+    // Macro is used by path, the enum must be in scope
+    use inner::Level;
+    assert_eq!(inner::level_n!("debug"), Level::Debug);
+}
+
+#[test]
+fn lookup() {
+    macro_rules! check_lookup {
+        ($lookup:literal) => {{
+            #[derive(EnumMap, Debug, Eq, PartialEq)]
+            #[enum_map(n(lookup = $lookup, trim, ignore_ascii_case, default = Unknown))]
+            enum E {
+                #[mapstr("alpha", name = "n", aliases("a"))]
+                A,
+                #[mapstr("beta")]
+                B,
+                #[mapstr("bets")]
+                C,
+                #[mapstr("gamma_{0}")]
+                D(u8),
+                #[mapstr("unknown")]
+                Unknown,
+            }
+
+            assert_eq!(E::from_n("alpha"), E::A);
+            assert_eq!(E::from_n(" A "), E::A);
+            assert_eq!(E::from_n("BETA"), E::B);
+            assert_eq!(E::from_n("bets"), E::C);
+            assert_eq!(E::from_n("gamma_2"), E::D(2));
+            assert_eq!(E::from_n("unknown"), E::Unknown);
+            assert_eq!(E::from_n("bet"), E::Unknown);
+            assert_eq!(E::from_n("betx"), E::Unknown);
+            assert_eq!(E::from_n(""), E::Unknown);
+        }};
+    }
+
+    check_lookup!("match");
+    check_lookup!("tree");
+    check_lookup!("phf");
+}
+
+#[test]
+fn parse_prefix() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum Op {
+        #[mapstr("<", name = "sym", parse_prefix)]
+        Lt,
+        #[mapstr("<=")]
+        Le,
+        #[mapstr("<<=")]
+        ShlAssign,
+        #[mapstr("=")]
+        Assign,
+        #[mapstr("==", aliases("==="))]
+        Eq,
+        #[mapstr("é")]
+        E,
+        #[mapstr("#{0}")]
+        Hash(u8),
+    }
+
+    assert_eq!(Op::parse_prefix_sym("<=x"), Some((Op::Le, "x")));
+    assert_eq!(Op::parse_prefix_sym("<x"), Some((Op::Lt, "x")));
+    assert_eq!(Op::parse_prefix_sym("<<x"), Some((Op::Lt, "<x")));
+    assert_eq!(Op::parse_prefix_sym("<<="), Some((Op::ShlAssign, "")));
+    assert_eq!(Op::parse_prefix_sym("===="), Some((Op::Eq, "=")));
+    assert_eq!(Op::parse_prefix_sym("éa"), Some((Op::E, "a")));
+    assert_eq!(Op::parse_prefix_sym("#1"), None);
+    assert_eq!(Op::parse_prefix_sym("x<"), None);
+    assert_eq!(Op::parse_prefix_sym(""), None);
+    assert_eq!(Op::Hash(1).to_sym(), "#1");
+
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum Keyword {
+        #[mapstr("fn", name = "kw", ignore_ascii_case, parse_prefix)]
+        Fn,
+        #[mapstr("for")]
+        For,
+    }
+
+    assert_eq!(Keyword::parse_prefix_kw("FOR x"), Some((Keyword::For, " x")));
+    assert_eq!(Keyword::parse_prefix_kw("Fn()"), Some((Keyword::Fn, "()")));
+    assert_eq!(Keyword::parse_prefix_kw("fo"), None);
+
+    // Not created without `parse_prefix`, so own function with the same name is allowed
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum Own {
+        #[mapstr("a", name = "n")]
+        A,
+    }
+
+    impl Own {
+        fn parse_prefix_n(s: &str) -> Option<(Self, &str)> {
+            Some((Self::A, s))
+        }
+    }
+
+    assert_eq!(Own::parse_prefix_n("b"), Some((Own::A, "b")));
+}
+
+#[test]
+fn sql_ddl() {
+    #[derive(EnumMap, Debug, Eq, PartialEq)]
+    enum OrderState {
+        #[mapstr("new", name = "state", aliases("fresh"), sql_ddl)]
+        #[mapstr("N", name = "short")]
+        New,
+        #[mapstr("customer's")]
+        #[mapstr("C")]
+        Customers,
+        #[mapstr("new", allow_duplicate, primary)]
+        Renewed,
+        #[mapstr("other", default_to = "unknown")]
+        Other,
+        Cancelled,
+    }
+
+    assert_eq!(OrderState::STATE_SQL_CHECK, r#"CHECK ("state" IN ('new', 'customer''s', 'other', 'unknown'))"#);
+    assert_eq!(
+        OrderState::state_sql_check("order \"state\""),
+        r#"CHECK ("order ""state""" IN ('new', 'customer''s', 'other', 'unknown'))"#
+    );
+    assert_eq!(
+        OrderState::STATE_PG_ENUM_DDL,
+        r#"CREATE TYPE "order_state_state" AS ENUM ('new', 'customer''s', 'other', 'unknown')"#
+    );
+    let all = [
+        OrderState::New,
+        OrderState::Customers,
+        OrderState::Renewed,
+        OrderState::Other,
+        OrderState::Cancelled,
+    ];
+    assert_eq!(all.map(|v| v.to_state()), ["new", "customer's", "new", "other", "unknown"]);
+    assert_eq!(OrderState::Customers.try_to_short(), Some("C"));
+}